@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2024, 2);
 
 struct Report(Vec<u32>);
 
@@ -78,13 +78,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4));
     }
 
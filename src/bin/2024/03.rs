@@ -1,10 +1,10 @@
 use pest::Parser;
 use pest_derive::Parser;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2024, 3);
 
 #[derive(Parser)]
-#[grammar = "src/bin/03.pest"]
+#[grammar = "src/bin/2024/03.pest"]
 pub struct MulParser;
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -53,13 +53,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(1389749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(918285));
     }
 }
@@ -0,0 +1,43 @@
+use advent_of_code::pattern::{find_words, Pattern, Transform};
+
+advent_of_code::solution!(2024, 4);
+
+struct Grid(Vec<Vec<char>>);
+
+impl From<&str> for Grid {
+    fn from(value: &str) -> Self {
+        Self(value.lines().map(|l| l.chars().collect()).collect())
+    }
+}
+
+/// Two `MAS` crossing on their `A`, each one read either way.
+const X_MAS: &str = "M.S\n.A.\nM.S";
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = Grid::from(input);
+    let word: Vec<char> = "XMAS".chars().collect();
+    Some(find_words(&grid.0, &word).count() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = Grid::from(input);
+    let x_mas = Pattern::parse(X_MAS, '.');
+    Some(x_mas.find(&grid.0, Transform::all()).count() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(9));
+    }
+}
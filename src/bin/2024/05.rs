@@ -0,0 +1,204 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+};
+advent_of_code::solution!(2024, 5);
+
+struct Rule {
+    before: u32,
+    after: u32,
+}
+
+impl From<&str> for Rule {
+    fn from(value: &str) -> Self {
+        let (before, after) = value.split_once("|").unwrap();
+        Rule {
+            before: before.parse().unwrap(),
+            after: after.parse().unwrap(),
+        }
+    }
+}
+
+/// Pages of an update that the rules require to come each before the next, and the last before
+/// the first.
+#[derive(Debug, PartialEq, Eq)]
+struct Cycle(Vec<u32>);
+
+impl Error for Cycle {}
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "contradictory rules: ")?;
+        for page in &self.0 {
+            write!(f, "{page} before ")?;
+        }
+        write!(f, "{}", self.0[0])
+    }
+}
+
+/// For each page, the pages that must come after it when both are in an update.
+struct RuleSet(HashMap<u32, HashSet<u32>>);
+
+impl From<Vec<Rule>> for RuleSet {
+    fn from(rules: Vec<Rule>) -> Self {
+        let mut map: HashMap<u32, HashSet<u32>> = HashMap::new();
+        for rule in rules {
+            map.entry(rule.before).or_default().insert(rule.after);
+        }
+        Self(map)
+    }
+}
+
+impl RuleSet {
+    fn must_precede(&self, before: u32, after: u32) -> bool {
+        self.0.get(&before).is_some_and(|set| set.contains(&after))
+    }
+
+    /// Whether no page of `update` comes after a page it must precede.
+    fn is_ordered(&self, update: &[u32]) -> bool {
+        let mut seen = HashSet::new();
+        update.iter().all(|&page| {
+            let ordered = self
+                .0
+                .get(&page)
+                .is_none_or(|after| after.iter().all(|a| !seen.contains(a)));
+            seen.insert(page);
+            ordered
+        })
+    }
+
+    /// `update` sorted along the rules between its pages, pages left free by the rules keeping
+    /// their relative order. Fails if those rules have a cycle.
+    fn reorder(&self, update: &[u32]) -> Result<Vec<u32>, Cycle> {
+        let n = update.len();
+        // edges between positions in the update, so that repeated pages stay apart
+        let mut successors = vec![Vec::new(); n];
+        let mut predecessors = vec![Vec::new(); n];
+        for (i, j) in (0..n).flat_map(|i| (0..n).map(move |j| (i, j))) {
+            if self.must_precede(update[i], update[j]) {
+                successors[i].push(j);
+                predecessors[j].push(i);
+            }
+        }
+
+        // Kahn's algorithm, taking the earliest free page first
+        let mut in_degree: Vec<usize> = predecessors.iter().map(Vec::len).collect();
+        let mut ready: BinaryHeap<Reverse<usize>> =
+            (0..n).filter(|&i| in_degree[i] == 0).map(Reverse).collect();
+        let mut sorted = Vec::with_capacity(n);
+        while let Some(Reverse(i)) = ready.pop() {
+            sorted.push(update[i]);
+            for &j in &successors[i] {
+                in_degree[j] -= 1;
+                if in_degree[j] == 0 {
+                    ready.push(Reverse(j));
+                }
+            }
+        }
+        if sorted.len() == n {
+            return Ok(sorted);
+        }
+
+        // every page left has a predecessor left: walking back from one must loop
+        let mut path = vec![(0..n).find(|&i| in_degree[i] > 0).unwrap()];
+        loop {
+            let last = *path.last().unwrap();
+            let previous = *predecessors[last]
+                .iter()
+                .find(|&&p| in_degree[p] > 0)
+                .unwrap();
+            if let Some(start) = path.iter().position(|&p| p == previous) {
+                let mut cycle: Vec<u32> = path[start..].iter().map(|&i| update[i]).collect();
+                cycle.reverse();
+                return Err(Cycle(cycle));
+            }
+            path.push(previous);
+        }
+    }
+}
+
+struct Print(Vec<u32>);
+
+impl From<&str> for Print {
+    fn from(value: &str) -> Self {
+        Print(value.split(",").map(|n| n.parse().unwrap()).collect())
+    }
+}
+
+impl Print {
+    fn get_middle(&self) -> u32 {
+        let len = self.0.len();
+        self.0[(len - 1) / 2]
+    }
+}
+
+fn parse(input: &str) -> (RuleSet, Vec<Print>) {
+    let (rules, prints) = input.split_once("\n\n").unwrap();
+    let rules: Vec<Rule> = rules.lines().map(Rule::from).collect();
+    let prints = prints.lines().map(Print::from).collect();
+    (RuleSet::from(rules), prints)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (rules, prints) = parse(input);
+
+    Some(
+        prints
+            .iter()
+            .filter(|p| rules.is_ordered(&p.0))
+            .map(|p| p.get_middle())
+            .sum(),
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (rules, prints) = parse(input);
+
+    let sum = prints
+        .iter()
+        .filter(|p| !rules.is_ordered(&p.0))
+        .map(|p| rules.reorder(&p.0).map(|sorted| Print(sorted).get_middle()))
+        .sum::<Result<u32, Cycle>>();
+    sum.inspect_err(|err| eprintln!("{err}")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(143));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(123));
+    }
+
+    #[test]
+    fn test_reorder_reports_cycles() {
+        let rules: Vec<Rule> = ["1|2", "2|3", "3|1", "4|1"]
+            .into_iter()
+            .map(Rule::from)
+            .collect();
+        let rules = RuleSet::from(rules);
+        assert!(rules.is_ordered(&[4, 1, 2]));
+        assert_eq!(rules.reorder(&[2, 1, 5]), Ok(vec![1, 2, 5]));
+
+        let Err(cycle) = rules.reorder(&[5, 3, 2, 1, 4]) else {
+            panic!("the rules between 1, 2 and 3 loop");
+        };
+        let mut pages = cycle.0.clone();
+        pages.sort();
+        assert_eq!(pages, vec![1, 2, 3]);
+        let (first, rest) = cycle.0.split_first().unwrap();
+        assert!(cycle.0.windows(2).all(|w| rules.must_precede(w[0], w[1])));
+        assert!(rules.must_precede(*rest.last().unwrap(), *first));
+        assert!(cycle.to_string().starts_with("contradictory rules: "));
+    }
+}
@@ -1,11 +1,11 @@
 use std::collections::BTreeSet;
-use std::collections::HashSet;
 
+use advent_of_code::bitset::BitSet;
 use advent_of_code::Bound;
 use advent_of_code::FromChar;
 use advent_of_code::{Dir, Pos};
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2024, 6);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum MapTile {
@@ -72,9 +72,6 @@ impl Puzzle {
     fn get(&self, pos: Pos) -> MapTile {
         self.map[pos.y][pos.x]
     }
-    fn get_mut(&mut self, pos: Pos) -> &mut MapTile {
-        &mut self.map[pos.y][pos.x]
-    }
     fn next_pos_dir(&self, pos: Pos, dir: Dir) -> Option<(Pos, Dir)> {
         let Some(new_pos) = dir.apply_bounded(&pos, &self.bound) else {
             // pos got out of bound
@@ -117,7 +114,7 @@ impl Puzzle {
         covered_pos
     }
 
-    /// List **potential** loop-creating obstacle positions.
+    // List **potential** loop-creating obstacle positions.
     // fn list_potential_loops(&self) -> Vec<(BoundedPos, Dir)> {
     //     let covered_pos = self.path_until_out();
     //     let mut potential_pos = Vec::new();
@@ -224,22 +221,22 @@ impl Puzzle {
     //     potential_pos
     // }
 
-    /// ```txt
-    /// . . . A . . . . .  
-    /// . . . . . . . . #  
-    /// . . . . . . . . .  
-    /// . . . . . . . . .  
-    /// . . N . . . . v .  
-    /// . . . . . . . B .  
-    /// ```
-    ///
-    /// - We must compute obs `N`.
-    /// - We know `B` (obstacle hit before `N`) and `A` (obstacle hit after `N`).
-    /// - We also know that we encounter `B` with `dir` (then we encounter `N` with `dir.rotate_right()`)
-    ///
-    /// ## Return
-    ///
-    /// - None if couldn't loop
+    // ```txt
+    // . . . A . . . . .
+    // . . . . . . . . #
+    // . . . . . . . . .
+    // . . . . . . . . .
+    // . . N . . . . v .
+    // . . . . . . . B .
+    // ```
+    //
+    // - We must compute obs `N`.
+    // - We know `B` (obstacle hit before `N`) and `A` (obstacle hit after `N`).
+    // - We also know that we encounter `B` with `dir` (then we encounter `N` with `dir.rotate_right()`)
+    //
+    // ## Return
+    //
+    // - None if couldn't loop
     // fn compute_nth(
     //     before_obs: (BoundedPos, Dir),
     //     after_obs: (BoundedPos, Dir),
@@ -256,34 +253,6 @@ impl Puzzle {
     //     None
     // }
 
-    /// Returns `true` if loops.
-    fn walk_detect_loop(
-        &self,
-        start_pos: Pos,
-        start_dir: Dir,
-        // history: &HashSet<(BoundedPos, Dir)>,
-    ) -> bool {
-        // // clone precedent history for faster detection
-        // dbg!(start_pos, start_dir);
-        let mut pos_dir_history = HashSet::new();
-        pos_dir_history.insert((start_pos, start_dir));
-
-        let mut current_pos = start_pos;
-        let mut current_dir = start_dir;
-
-        while let Some((new_pos, new_dir)) = self.next_pos_dir(current_pos, current_dir) {
-            if pos_dir_history.contains(&(new_pos, new_dir)) {
-                // LOOP!
-                return true;
-            }
-            pos_dir_history.insert((new_pos, new_dir));
-            current_pos = new_pos;
-            current_dir = new_dir;
-        }
-        // out of bounds
-        false
-    }
-
     // fn effective_loop_possibilities(&mut self) -> usize {
     //     let mut reachable_loops = 0;
     //     let mut potential_loop_obs = self.list_potential_loops();
@@ -347,28 +316,146 @@ impl Puzzle {
     //     reachable_loops
     // }
 
-    fn count_possible_loop_obs(&mut self) -> usize {
+    fn count_possible_loop_obs(&self) -> usize {
         let path = self.path_until_out();
+        let mut jumps = JumpTable::new(self);
+        let mut turns = BitSet::new(self.bound.x_bound * self.bound.y_bound * 4);
+
+        // the obstacle must be where the guard first walks into it, the path before is unchanged
+        let mut visited = BitSet::new(self.bound.x_bound * self.bound.y_bound);
+        visited.insert(jumps.cell(self.guard_pos));
+        let mut loops = 0;
+        for (&(before_pos, dir), &(pos, _)) in path.iter().zip(path.iter().skip(1)) {
+            if pos == before_pos || !visited.insert(jumps.cell(pos)) {
+                continue;
+            }
+            let patch = jumps.add_obstacle(pos);
+            turns.clear();
+            if jumps.loops(before_pos, dir, &mut turns) {
+                loops += 1;
+            }
+            jumps.undo(patch);
+        }
+        loops
+    }
+}
+
+/// Cell where the guard stops, facing an obstacle, when walking from each cell in each
+/// direction: the guard jumps from turn to turn instead of walking.
+struct JumpTable {
+    width: usize,
+    /// Per cell, per direction, `EXIT` if the guard walks out of the map.
+    stops: Vec<[u32; 4]>,
+}
+
+const EXIT: u32 = u32::MAX;
+/// Entries of obstacle cells, where the guard never stands.
+const WALL: u32 = u32::MAX - 1;
 
-        let mut validated_loop_pos = BTreeSet::new();
-        let mut eliminated_loop_pos = BTreeSet::new();
-        for (pos, dir) in path {
-            if pos == self.guard_pos && dir == self.guard_dir {
-                continue; // skip pos 0
+/// Entries changed by [`JumpTable::add_obstacle`], with their previous value.
+struct Patch(Vec<(usize, Dir, u32)>);
+
+impl JumpTable {
+    fn new(puzzle: &Puzzle) -> Self {
+        let Bound { x_bound, y_bound } = puzzle.bound;
+        let mut table = Self {
+            width: x_bound,
+            stops: vec![[EXIT; 4]; x_bound * y_bound],
+        };
+        for dir in Dir::all() {
+            // sweep each line from the side the guard walks towards
+            let lines: Vec<Vec<Pos>> = match dir {
+                Dir::Up | Dir::Down => (0..x_bound)
+                    .map(|x| (0..y_bound).map(|y| Pos { x, y }).collect())
+                    .collect(),
+                Dir::Left | Dir::Right => (0..y_bound)
+                    .map(|y| (0..x_bound).map(|x| Pos { x, y }).collect())
+                    .collect(),
+            };
+            for mut line in lines {
+                if matches!(dir, Dir::Down | Dir::Right) {
+                    line.reverse();
+                }
+                let mut stop = EXIT;
+                for pos in line {
+                    let cell = table.cell(pos);
+                    match puzzle.get(pos) {
+                        MapTile::Obstacle => {
+                            table.stops[cell][dir as usize] = WALL;
+                            stop = dir
+                                .opposite()
+                                .apply_bounded(&pos, &puzzle.bound)
+                                .map_or(EXIT, |p| table.cell(p) as u32)
+                        }
+                        MapTile::Empty => table.stops[cell][dir as usize] = stop,
+                    }
+                }
             }
-            if eliminated_loop_pos.contains(&pos) {
-                continue; // skip
+        }
+        table
+    }
+
+    fn cell(&self, pos: Pos) -> usize {
+        pos.y * self.width + pos.x
+    }
+
+    fn bound(&self) -> Bound {
+        Bound {
+            x_bound: self.width,
+            y_bound: self.stops.len() / self.width,
+        }
+    }
+
+    /// Makes every cell that walks into `obstacle` stop in front of it.
+    fn add_obstacle(&mut self, obstacle: Pos) -> Patch {
+        let bound = self.bound();
+        let mut patch = Vec::new();
+        for dir in Dir::all() {
+            let Some(stop) = dir.opposite().apply_bounded(&obstacle, &bound) else {
+                continue;
+            };
+            let stop_cell = self.cell(stop);
+            // cells walking into `obstacle` all shared the stop behind it
+            let previous = self.stops[stop_cell][dir as usize];
+            if previous == WALL {
+                continue;
+            }
+            let mut pos = Some(stop);
+            while let Some(p) = pos {
+                let cell = self.cell(p);
+                if self.stops[cell][dir as usize] != previous {
+                    break;
+                }
+                patch.push((cell, dir, previous));
+                self.stops[cell][dir as usize] = stop_cell as u32;
+                pos = dir.opposite().apply_bounded(&p, &bound);
+            }
+        }
+        Patch(patch)
+    }
+
+    fn undo(&mut self, patch: Patch) {
+        for (cell, dir, stop) in patch.0.into_iter().rev() {
+            self.stops[cell][dir as usize] = stop;
+        }
+    }
+
+    /// Whether the guard, at `pos` facing `dir`, ends up in a loop. `turns` must be empty, and
+    /// is left with every turn taken.
+    fn loops(&self, pos: Pos, dir: Dir, turns: &mut BitSet) -> bool {
+        let mut cell = self.cell(pos);
+        let mut dir = dir;
+        loop {
+            let stop = self.stops[cell][dir as usize];
+            if stop == EXIT {
+                return false;
             }
-            let before_pos = dir.opposite().apply_bounded(&pos, &self.bound).unwrap();
-            *self.get_mut(pos) = MapTile::Obstacle;
-            if self.walk_detect_loop(before_pos, dir) {
-                validated_loop_pos.insert(pos);
-            } else {
-                eliminated_loop_pos.insert(pos);
+            cell = stop as usize;
+            dir = dir.rotate_right();
+            if !turns.insert(cell * 4 + dir as usize) {
+                return true;
             }
-            *self.get_mut(pos) = MapTile::Empty;
         }
-        validated_loop_pos.len()
     }
 }
 
@@ -378,26 +465,67 @@ pub fn part_one(input: &str) -> Option<usize> {
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let mut puzzle = Puzzle::from(input);
+    let puzzle = Puzzle::from(input);
     Some(puzzle.count_possible_loop_obs())
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(41));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 
+    #[test]
+    fn jump_table_matches_walk() {
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let puzzle = Puzzle::from(input.as_str());
+        let mut jumps = JumpTable::new(&puzzle);
+        let original = jumps.stops.clone();
+        let mut turns = BitSet::new(puzzle.bound.x_bound * puzzle.bound.y_bound * 4);
+        for y in 0..puzzle.bound.y_bound {
+            for x in 0..puzzle.bound.x_bound {
+                let obstacle = Pos { x, y };
+                if puzzle.get(obstacle) == MapTile::Obstacle || obstacle == puzzle.guard_pos {
+                    continue;
+                }
+                let mut walked = Puzzle::from(input.as_str());
+                walked.map[y][x] = MapTile::Obstacle;
+                let mut seen = HashSet::new();
+                let mut state = Some((walked.guard_pos, walked.guard_dir));
+                let mut walk_loops = false;
+                while let Some((pos, dir)) = state {
+                    if !seen.insert((pos, dir)) {
+                        walk_loops = true;
+                        break;
+                    }
+                    state = walked.next_pos_dir(pos, dir);
+                }
+
+                let patch = jumps.add_obstacle(obstacle);
+                turns.clear();
+                assert_eq!(
+                    jumps.loops(puzzle.guard_pos, puzzle.guard_dir, &mut turns),
+                    walk_loops,
+                    "obstacle at {obstacle:?}"
+                );
+                jumps.undo(patch);
+                assert!(jumps.stops == original);
+            }
+        }
+    }
+
     #[test]
     fn hash_set_obs() {
         let mut set = HashSet::new();
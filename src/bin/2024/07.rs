@@ -0,0 +1,288 @@
+advent_of_code::solution!(2024, 7);
+
+struct Equation {
+    test_val: usize,
+    nums: Vec<usize>,
+}
+
+/// What the numbers left of an operator must give for the equation to hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Left {
+    Value(usize),
+    /// Any value does, e.g. on the left of `* 0` when the result is 0.
+    Anything,
+}
+
+/// A binary operator of the equations, which are evaluated left to right.
+trait Operator {
+    fn symbol(&self) -> &'static str;
+
+    /// `a op b`, or `None` if it overflows.
+    fn apply(&self, a: usize, b: usize) -> Option<usize>;
+
+    /// The `a` for which `a op b == result`, if any.
+    fn undo(&self, result: usize, b: usize) -> Option<Left>;
+}
+
+struct Add;
+struct Mul;
+struct Concat;
+
+impl Operator for Add {
+    fn symbol(&self) -> &'static str {
+        "+"
+    }
+    fn apply(&self, a: usize, b: usize) -> Option<usize> {
+        a.checked_add(b)
+    }
+    fn undo(&self, result: usize, b: usize) -> Option<Left> {
+        result.checked_sub(b).map(Left::Value)
+    }
+}
+
+impl Operator for Mul {
+    fn symbol(&self) -> &'static str {
+        "*"
+    }
+    fn apply(&self, a: usize, b: usize) -> Option<usize> {
+        a.checked_mul(b)
+    }
+    fn undo(&self, result: usize, b: usize) -> Option<Left> {
+        if b == 0 {
+            return (result == 0).then_some(Left::Anything);
+        }
+        result.is_multiple_of(b).then_some(Left::Value(result / b))
+    }
+}
+
+impl Concat {
+    /// `10` to the number of digits of `b`.
+    fn shift(b: usize) -> Option<usize> {
+        10usize.checked_pow(b.checked_ilog10().unwrap_or(0) + 1)
+    }
+}
+
+impl Operator for Concat {
+    fn symbol(&self) -> &'static str {
+        "||"
+    }
+    fn apply(&self, a: usize, b: usize) -> Option<usize> {
+        a.checked_mul(Self::shift(b)?)?.checked_add(b)
+    }
+    fn undo(&self, result: usize, b: usize) -> Option<Left> {
+        // `b` has as many digits as `usize::MAX`: only `0 || b` fits
+        let Some(shift) = Self::shift(b) else {
+            return (result == b).then_some(Left::Value(0));
+        };
+        (result % shift == b).then_some(Left::Value(result / shift))
+    }
+}
+
+const PART_ONE: [&dyn Operator; 2] = [&Add, &Mul];
+const PART_TWO: [&dyn Operator; 3] = [&Add, &Mul, &Concat];
+
+impl From<&str> for Equation {
+    fn from(line: &str) -> Self {
+        let (test_val, nums) = line.split_once(":").unwrap();
+        let test_val: usize = test_val.parse().unwrap();
+        let nums = nums.split(" ");
+        let nums: Vec<usize> = nums
+            .filter(|s| !s.is_empty())
+            .map(|n| n.parse().unwrap())
+            .collect();
+
+        Self { test_val, nums }
+    }
+}
+
+impl Equation {
+    /// Operators between the numbers that give the test value, if any.
+    ///
+    /// Searches from the last number back: each operator must be undone on what is left of the
+    /// test value, which rules out most of them (a product must divide, a concatenation must
+    /// end with the number...).
+    fn solve<'a>(&self, operators: &[&'a dyn Operator]) -> Option<Vec<&'a dyn Operator>> {
+        let mut used = Vec::with_capacity(self.nums.len());
+        if self.undo(self.test_val, self.nums.len(), operators, &mut used) {
+            used.reverse();
+            Some(used)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the first `n` numbers can give `target`, pushing the operators used from the
+    /// last one.
+    fn undo<'a>(
+        &self,
+        target: usize,
+        n: usize,
+        operators: &[&'a dyn Operator],
+        used: &mut Vec<&'a dyn Operator>,
+    ) -> bool {
+        if n == 1 {
+            return target == self.nums[0];
+        }
+        let last = self.nums[n - 1];
+        for &op in operators {
+            let Some(left) = op.undo(target, last) else {
+                continue;
+            };
+            used.push(op);
+            match left {
+                Left::Value(rest) => {
+                    if self.undo(rest, n - 1, operators, used) {
+                        return true;
+                    }
+                }
+                Left::Anything => {
+                    if let Some(ops) = self.any_value(self.nums[0], 1, n - 1, operators) {
+                        used.extend(ops.into_iter().rev());
+                        return true;
+                    }
+                }
+            }
+            used.pop();
+        }
+        false
+    }
+
+    /// Operators between the first `n` numbers that give any value without overflowing,
+    /// `acc` being the value of the first `i` ones.
+    fn any_value<'a>(
+        &self,
+        acc: usize,
+        i: usize,
+        n: usize,
+        operators: &[&'a dyn Operator],
+    ) -> Option<Vec<&'a dyn Operator>> {
+        if i == n {
+            return Some(Vec::with_capacity(n));
+        }
+        operators.iter().find_map(|&op| {
+            let acc = op.apply(acc, self.nums[i])?;
+            let mut rest = self.any_value(acc, i + 1, n, operators)?;
+            rest.insert(0, op);
+            Some(rest)
+        })
+    }
+
+    /// Value of the equation with `operators` between its numbers, `None` on overflow.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn evaluate(&self, operators: &[&dyn Operator]) -> Option<usize> {
+        assert_eq!(operators.len() + 1, self.nums.len());
+        self.nums[1..]
+            .iter()
+            .zip(operators)
+            .try_fold(self.nums[0], |acc, (&n, op)| op.apply(acc, n))
+    }
+
+    /// The equation with `operators` filled in, e.g. `3267 = 81 + 40 * 27`.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn render(&self, operators: &[&dyn Operator]) -> String {
+        let mut equation = format!("{} = {}", self.test_val, self.nums[0]);
+        for (n, op) in self.nums[1..].iter().zip(operators) {
+            equation += &format!(" {} {n}", op.symbol());
+        }
+        equation
+    }
+}
+
+fn calibration(input: &str, operators: &[&dyn Operator]) -> usize {
+    input
+        .lines()
+        .map(Equation::from)
+        .filter(|e| e.solve(operators).is_some())
+        .map(|e| e.test_val)
+        .sum()
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(calibration(input, &PART_ONE))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(calibration(input, &PART_TWO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(3749));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(11387));
+    }
+
+    #[test]
+    fn test_operator_sequences() {
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let solved: Vec<String> = input
+            .lines()
+            .map(Equation::from)
+            .filter_map(|e| {
+                let ops = e.solve(&PART_TWO)?;
+                assert_eq!(e.evaluate(&ops), Some(e.test_val));
+                Some(e.render(&ops))
+            })
+            .collect();
+        assert_eq!(
+            solved,
+            vec![
+                "190 = 10 * 19",
+                "3267 = 81 * 40 + 27",
+                "156 = 15 || 6",
+                "7290 = 6 * 8 || 6 * 15",
+                "192 = 17 || 8 + 14",
+                "292 = 11 + 6 * 16 + 20",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_concat_overflow() {
+        assert_eq!(Concat.apply(12, 345), Some(12345));
+        assert_eq!(Concat.undo(12345, 345), Some(Left::Value(12)));
+        assert_eq!(Concat.undo(12345, 45), Some(Left::Value(123)));
+        assert_eq!(Concat.undo(12345, 5), Some(Left::Value(1234)));
+        assert_eq!(Concat.undo(12345, 44), None);
+        assert_eq!(Concat.apply(usize::MAX / 10, 99), None);
+        assert_eq!(Concat.apply(1, usize::MAX), None);
+
+        let eq = Equation::from(&*format!("{}: {} 9", usize::MAX, usize::MAX / 10));
+        assert!(eq.solve(&PART_TWO).is_none());
+    }
+
+    #[test]
+    fn test_zero_operands() {
+        assert_eq!(Mul.undo(0, 0), Some(Left::Anything));
+        assert_eq!(Mul.undo(5, 0), None);
+        assert_eq!(Concat.undo(120, 0), Some(Left::Value(12)));
+
+        for (line, expected) in [
+            ("12: 7 0 12", Some("12 = 7 * 0 + 12")),
+            ("0: 3 4 0", Some("0 = 3 + 4 * 0")),
+            ("7: 3 0 4", Some("7 = 3 + 0 + 4")),
+            ("5: 0 5", Some("5 = 0 + 5")),
+            ("9: 3 0", None),
+        ] {
+            let eq = Equation::from(line);
+            let solved = eq.solve(&PART_ONE).map(|ops| {
+                assert_eq!(eq.evaluate(&ops), Some(eq.test_val));
+                eq.render(&ops)
+            });
+            assert_eq!(solved.as_deref(), expected, "{line}");
+        }
+        // the numbers left of `* 0` must still not overflow
+        let eq = Equation::from(&*format!("0: {} 2 0", usize::MAX));
+        assert_eq!(eq.solve(&PART_ONE).map(|ops| eq.render(&ops)), None);
+        assert_eq!(eq.solve(&PART_TWO).map(|ops| eq.render(&ops)), None);
+    }
+}
@@ -1,9 +1,9 @@
 use std::collections::{BTreeSet, HashMap};
 
-use advent_of_code::{Bound, DirVec, Pos};
+use advent_of_code::{lattice, Bound, DirVec, Pos};
 use itertools::Itertools;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2024, 8);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Tile {
@@ -116,27 +116,8 @@ impl Map {
                 let antenna_a = *pair[0];
                 let antenna_b = *pair[1];
 
-                let a_to_b = DirVec::new(antenna_a, antenna_b);
-
-                let mut current = antenna_a;
-
-                while let Some(antinode1) = a_to_b
-                    .apply(current)
-                    .filter(|&p| self.bounds.check(p).is_some())
-                {
-                    current = antinode1;
-                    spots.insert(antinode1);
-                }
-
-                current = antenna_b;
-                let b_to_a = a_to_b.opposite();
-                while let Some(antinode2) = b_to_a
-                    .apply(current)
-                    .filter(|&p| self.bounds.check(p).is_some())
-                {
-                    current = antinode2;
-                    spots.insert(antinode2);
-                }
+                // antennas may be several steps apart: points in between count too
+                spots.extend(lattice::line(antenna_a, antenna_b, &self.bounds));
             }
         }
 
@@ -195,13 +176,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(14));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(34));
     }
+
+    #[test]
+    fn test_part_two_non_primitive_offsets() {
+        // antennas (2, 1) apart, then (4, 2) apart: same line, same points
+        assert_eq!(part_two("a.........\n..a.......\n.........."), Some(3));
+        assert_eq!(part_two("a.........\n..........\n....a....."), Some(3));
+        assert_eq!(part_two("a...\n....\n....\n...a"), Some(4));
+    }
 }
@@ -0,0 +1,265 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{Debug, Write},
+};
+
+advent_of_code::solution!(2024, 9);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    start: usize,
+    size: usize,
+    file_id: usize,
+}
+
+/// A run of free blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Span {
+    start: usize,
+    size: usize,
+}
+
+/// Files never move right, so a file only ever uses the gaps found left of it when the disk was
+/// read: the space it leaves behind is never reused, and is not tracked.
+struct DiskMap {
+    /// Every file, or piece of file, in no particular order once moved.
+    entries: Vec<Entry>,
+    /// Gaps between the files, by ascending start.
+    free: Vec<Span>,
+    total_size: usize,
+}
+
+impl Debug for DiskMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut entries = self.entries.clone();
+        entries.sort_by_key(|e| e.start);
+        let mut written = 0;
+        for entry in &entries {
+            while entry.start > written {
+                f.write_char('.')?;
+                written += 1;
+            }
+            for _ in 0..entry.size {
+                f.write_fmt(format_args!("{}", entry.file_id))?;
+                written += 1;
+            }
+        }
+        while self.total_size > written {
+            f.write_char('.')?;
+            written += 1;
+        }
+        f.write_char('\n')
+    }
+}
+
+impl From<&str> for DiskMap {
+    fn from(value: &str) -> Self {
+        let mut entries = Vec::new();
+        let mut free = Vec::new();
+        let mut next_entry_start = 0;
+        let sizes: Vec<usize> = value
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .map(|c| c.to_digit(10).unwrap() as usize)
+            .collect();
+        for (file_id, chunk) in sizes.chunks(2).enumerate() {
+            entries.push(Entry {
+                start: next_entry_start,
+                size: chunk[0],
+                file_id,
+            });
+            next_entry_start += chunk[0];
+            if let Some(&empty_s) = chunk.get(1) {
+                if empty_s > 0 {
+                    free.push(Span {
+                        start: next_entry_start,
+                        size: empty_s,
+                    });
+                }
+                next_entry_start += empty_s;
+            }
+        }
+
+        let last_entry = entries.last().unwrap();
+        let total_size = last_entry.start + last_entry.size;
+        Self {
+            entries,
+            free,
+            total_size,
+        }
+    }
+}
+
+/// Largest span a disk map can describe: sizes are single digits.
+const MAX_SPAN: usize = 9;
+
+/// Free spans grouped by size, each group a min-heap of start offsets.
+struct FreeSpans {
+    by_size: [BinaryHeap<Reverse<usize>>; MAX_SPAN + 1],
+}
+
+impl FreeSpans {
+    fn new(spans: &[Span]) -> Self {
+        let mut free = Self {
+            by_size: Default::default(),
+        };
+        spans.iter().for_each(|&span| free.insert(span));
+        free
+    }
+
+    fn insert(&mut self, span: Span) {
+        if span.size > 0 {
+            self.by_size[span.size].push(Reverse(span.start));
+        }
+    }
+
+    /// Removes the leftmost span of at least `min_size` blocks that starts before `before`.
+    fn take_leftmost(&mut self, min_size: usize, before: usize) -> Option<Span> {
+        let (size, Reverse(start)) = (min_size..=MAX_SPAN)
+            .filter_map(|size| self.by_size[size].peek().map(|&start| (size, start)))
+            .max_by_key(|&(_, start)| start)?;
+        if start >= before {
+            return None;
+        }
+        self.by_size[size].pop();
+        Some(Span { start, size })
+    }
+}
+
+impl DiskMap {
+    /// Moves blocks one at a time from the end of the disk to the leftmost free block.
+    fn fragmented_reorganize(&mut self) {
+        let mut files = std::mem::take(&mut self.entries);
+        files.sort_by_key(|e| e.start);
+        let mut moved = Vec::new();
+        for span in &mut self.free {
+            while span.size > 0 {
+                let Some(last) = files.last_mut().filter(|last| last.start > span.start) else {
+                    break;
+                };
+                let size = span.size.min(last.size);
+                moved.push(Entry {
+                    start: span.start,
+                    size,
+                    file_id: last.file_id,
+                });
+                span.start += size;
+                span.size -= size;
+                last.size -= size;
+                if last.size == 0 {
+                    files.pop();
+                }
+            }
+        }
+        self.free.retain(|span| span.size > 0);
+        files.append(&mut moved);
+        self.entries = files;
+    }
+
+    /// helper
+    fn sum_consecutive(a: usize, b: usize) -> usize {
+        let n = a.abs_diff(b) + 1;
+        (n * (a + b)) / 2
+    }
+
+    fn checksum(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.size > 0)
+            .map(|e| e.file_id * Self::sum_consecutive(e.start, e.start + e.size - 1))
+            .sum()
+    }
+
+    /// Moves whole files, by decreasing id, to the leftmost gap that fits them.
+    fn unfragmented_reorganize(&mut self) {
+        let mut free = FreeSpans::new(&self.free);
+        for entry in self.entries.iter_mut().rev() {
+            if entry.size == 0 {
+                continue;
+            }
+            let Some(span) = free.take_leftmost(entry.size, entry.start) else {
+                continue;
+            };
+            entry.start = span.start;
+            free.insert(Span {
+                start: span.start + entry.size,
+                size: span.size - entry.size,
+            });
+        }
+        self.free = (1..=MAX_SPAN)
+            .flat_map(|size| {
+                free.by_size[size]
+                    .iter()
+                    .map(move |&Reverse(start)| Span { start, size })
+            })
+            .collect();
+        self.free.sort_by_key(|span| span.start);
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let mut disk_map = DiskMap::from(input);
+    disk_map.fragmented_reorganize();
+    Some(disk_map.checksum())
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut disk_map = DiskMap::from(input);
+    disk_map.unfragmented_reorganize();
+    Some(disk_map.checksum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1928));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(2858));
+    }
+
+    #[test]
+    fn test_debug_rendering() {
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let disk_map = DiskMap::from(input.as_str());
+        assert_eq!(
+            format!("{disk_map:?}"),
+            "00...111...2...333.44.5555.6666.777.888899\n"
+        );
+
+        let mut fragmented = DiskMap::from(input.as_str());
+        fragmented.fragmented_reorganize();
+        assert_eq!(
+            format!("{fragmented:?}"),
+            "0099811188827773336446555566..............\n"
+        );
+
+        let mut unfragmented = DiskMap::from(input.as_str());
+        unfragmented.unfragmented_reorganize();
+        assert_eq!(
+            format!("{unfragmented:?}"),
+            "00992111777.44.333....5555.6666.....8888..\n"
+        );
+    }
+
+    #[test]
+    fn test_leftmost_fitting_span() {
+        let mut free = FreeSpans::new(&[
+            Span { start: 2, size: 1 },
+            Span { start: 5, size: 3 },
+            Span { start: 9, size: 2 },
+        ]);
+        assert_eq!(free.take_leftmost(2, 20), Some(Span { start: 5, size: 3 }));
+        assert_eq!(free.take_leftmost(2, 20), Some(Span { start: 9, size: 2 }));
+        assert_eq!(free.take_leftmost(1, 2), None);
+        assert_eq!(free.take_leftmost(4, 20), None);
+    }
+}
@@ -0,0 +1,74 @@
+use advent_of_code::{dag::PathCounts, Pos};
+
+advent_of_code::solution!(2024, 10);
+
+struct Map {
+    map: Vec<Vec<u8>>,
+}
+
+impl From<&str> for Map {
+    fn from(value: &str) -> Self {
+        let map = value
+            .lines()
+            .map(|l| l.chars().map(|c| c.to_digit(10).unwrap() as u8).collect())
+            .collect::<Vec<Vec<u8>>>();
+        Self { map }
+    }
+}
+
+impl Map {
+    const TRAILHEAD: u8 = 0;
+    const PEAK: u8 = 9;
+
+    /// Trails go up by exactly one at each step, so they all climb towards the peaks.
+    fn trails(&self) -> PathCounts {
+        PathCounts::new(
+            &self.map,
+            |&h| h as usize,
+            |&from, &to| to == from + 1,
+            |&h| h == Self::PEAK,
+        )
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.map.iter().enumerate().flat_map(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .filter(|(_, &h)| h == Self::TRAILHEAD)
+                .map(move |(x, _)| Pos { x, y })
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let map = Map::from(input);
+    let trails = map.trails();
+    Some(
+        map.trailheads()
+            .map(|pos| trails.reachable(pos).len())
+            .sum(),
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let map = Map::from(input);
+    let trails = map.trails();
+    Some(map.trailheads().map(|pos| trails.paths(pos)).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(36));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(81));
+    }
+}
@@ -1,6 +1,6 @@
-use std::collections::BTreeMap;
+use advent_of_code::counter::BTreeCounter;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2024, 11);
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 struct Stone(usize);
@@ -83,43 +83,24 @@ impl Puzzle {
     // }
 
     fn cached_blinks(&self, blinks: usize) -> usize {
-        let mut cache: BTreeMap<Stone, usize> = BTreeMap::new();
-        for &stone in &self.0 {
-            cache.entry(stone).and_modify(|e| *e += 1).or_insert(1);
-        }
+        let mut cache: BTreeCounter<Stone> = self.0.iter().copied().collect();
 
         for _ in 0..blinks {
-            let mut new_stone_cache: BTreeMap<Stone, usize> = BTreeMap::new();
-            for (stone, c) in cache.into_iter() {
-                if c == 0 {
-                    continue;
-                }
+            let mut new_stone_cache = BTreeCounter::new();
+            for (stone, c) in cache {
                 if let Some(n_stone) = stone.rule1() {
-                    new_stone_cache
-                        .entry(n_stone)
-                        .and_modify(|n_c| *n_c += c)
-                        .or_insert(c);
+                    new_stone_cache.add(n_stone, c);
                 } else if let Some((n_stone1, n_stone2)) = stone.rule2() {
-                    new_stone_cache
-                        .entry(n_stone1)
-                        .and_modify(|n_c| *n_c += c)
-                        .or_insert(c);
-                    new_stone_cache
-                        .entry(n_stone2)
-                        .and_modify(|n_c| *n_c += c)
-                        .or_insert(c);
+                    new_stone_cache.add(n_stone1, c);
+                    new_stone_cache.add(n_stone2, c);
                 } else {
-                    let n_stone = stone.rule3();
-                    new_stone_cache
-                        .entry(n_stone)
-                        .and_modify(|n_c| *n_c += c)
-                        .or_insert(c);
+                    new_stone_cache.add(stone.rule3(), c);
                 }
             }
             cache = new_stone_cache;
         }
 
-        cache.into_iter().map(|(_s, c)| c).sum()
+        cache.total()
     }
 
     // fn cached_blinks_dirty(&self, blinks: usize, blinks_threshold: usize) -> usize {
@@ -199,13 +180,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(55312));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(65601038650482));
     }
 }
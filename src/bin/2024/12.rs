@@ -0,0 +1,83 @@
+use advent_of_code::region::Regions;
+
+advent_of_code::solution!(2024, 12);
+
+struct Map {
+    regions: Regions<char>,
+}
+
+impl From<&str> for Map {
+    fn from(value: &str) -> Self {
+        let map: Vec<Vec<char>> = value.lines().map(|l| l.chars().collect()).collect();
+        Map {
+            regions: Regions::new(&map),
+        }
+    }
+}
+
+impl Map {
+    fn fence_price(&self) -> usize {
+        self.regions.iter().map(|r| r.area * r.perimeter).sum()
+    }
+
+    fn fence_discount_price(&self) -> usize {
+        self.regions.iter().map(|r| r.area * r.sides).sum()
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let map = Map::from(input);
+    Some(map.fence_price())
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let map = Map::from(input);
+    Some(map.fence_discount_price())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1930));
+    }
+
+    #[test]
+    fn test_part_two_a() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1206));
+    }
+
+    #[test]
+    fn test_part_two_b() {
+        let input = r#"AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+"#;
+        let result = part_two(input);
+        assert_eq!(result, Some(368));
+
+        let e_input = r#"EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+"#;
+        let result = part_two(e_input);
+        assert_eq!(result, Some(236));
+    }
+
+    #[test]
+    fn test_part_two_c() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 2,
+        ));
+        assert_eq!(result, Some(5402));
+    }
+}
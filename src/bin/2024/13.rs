@@ -1,6 +1,6 @@
-use fraction::GenericFraction;
+use advent_of_code::math::min_cost_2x2;
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2024, 13);
 
 #[derive(Debug, Clone, Copy)]
 struct Button {
@@ -51,8 +51,6 @@ impl From<&str> for Prize {
     }
 }
 
-type F = GenericFraction<usize>;
-
 impl Prize {
     /// ## Return
     /// - Some(usize) -> min token cost of button pushes
@@ -60,19 +58,15 @@ impl Prize {
     fn solve_for(&self, button1: Button, button2: Button) -> Option<usize> {
         // f1 * dx1 + f2 * dx2 = X
         // f1 * dy1 + f2 * dy2 = Y
-        // <=>
-        // f1 = (X - f2 * dx2) / dx1
-        // (X - f2 * dx2) * dy1 / dx1 + f2 * dy2 = Y
-        // f2 * dy2 - (f2 * dx2 * dy1) / dx1 = Y - X * dy1 / dx1
-        // f2 (dy2 - dx2 * dy1 / dx1) = Y - X * dy1 / dx1
-        // f2 = (Y - X * dy1 / dx1) / (dy2 - dx2 * dy1 / dx1)
-        let dy1_dx1 = F::new(button1.dy, button1.dx);
-        let f2 = (F::from(self.y) - F::from(self.x) * dy1_dx1)
-            / (F::from(button2.dy) - F::from(button2.dx) * dy1_dx1);
-        let f1 = (F::from(self.x) - f2 * button2.dx) / F::from(button1.dx);
-        let f2: usize = f2.try_into().ok()?;
-        let f1: usize = f1.try_into().ok()?;
-        return Some(f1 * button1.cost + f2 * button2.cost);
+        let (f1, f2) = min_cost_2x2(
+            [
+                [button1.dx as i64, button2.dx as i64],
+                [button1.dy as i64, button2.dy as i64],
+            ],
+            [self.x as i64, self.y as i64],
+            [button1.cost as i64, button2.cost as i64],
+        )?;
+        Some(f1 as usize * button1.cost + f2 as usize * button2.cost)
     }
 }
 
@@ -116,13 +110,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(480));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(875318608908));
     }
 }
@@ -0,0 +1,366 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Write},
+};
+
+use advent_of_code::{math::crt, simulation::Simulation, template::artifact, Bound, DirVec, Pos};
+
+advent_of_code::solution!(2024, 14);
+
+#[derive(Debug)]
+enum Quarter {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+#[derive(Debug)]
+struct Map {
+    bound: Bound,
+}
+
+#[derive(Debug, Clone)]
+struct Robot {
+    pos: Pos,
+    vel: DirVec,
+}
+
+impl From<&str> for Robot {
+    fn from(value: &str) -> Self {
+        let (p, v) = value.split_once(" ").unwrap();
+        let (_, p_vals) = p.split_once("=").unwrap();
+        let (p_x, p_y) = p_vals.split_once(",").unwrap();
+        let p_x: usize = p_x.parse().unwrap();
+        let p_y: usize = p_y.parse().unwrap();
+
+        let (_, v_vals) = v.split_once("=").unwrap();
+        let (v_x, v_y) = v_vals.split_once(",").unwrap();
+        let v_x = v_x.parse().unwrap();
+        let v_y = v_y.parse().unwrap();
+
+        Self {
+            pos: Pos { x: p_x, y: p_y },
+            vel: DirVec { dx: v_x, dy: v_y },
+        }
+    }
+}
+
+struct Picture<'a>(&'a Map, &'a Vec<Robot>);
+
+impl<'a> Display for Picture<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.0.bound.y_bound {
+            for x in 0..self.0.bound.x_bound {
+                let count = self
+                    .1
+                    .iter()
+                    .filter(|&r| r.pos.x == x && r.pos.y == y)
+                    .count();
+
+                if count == 0 {
+                    f.write_char('.')?;
+                } else if count > 9 {
+                    f.write_char('N')?;
+                } else {
+                    f.write_fmt(format_args!("{}", count))?;
+                }
+            }
+            f.write_char('\n')?;
+        }
+        f.write_char('\n')
+    }
+}
+
+/// `n` times the variance of `values`, which is enough to compare spreads and stays an integer.
+fn spread(values: impl Iterator<Item = usize>) -> usize {
+    let (mut n, mut sum, mut sum_sq) = (0, 0, 0);
+    for v in values {
+        n += 1;
+        sum += v;
+        sum_sq += v * v;
+    }
+    if n == 0 {
+        return 0;
+    }
+    sum_sq - sum * sum / n
+}
+
+trait ProximityScore {
+    fn proximity_score(&self) -> usize;
+}
+
+impl ProximityScore for Vec<Robot> {
+    fn proximity_score(&self) -> usize {
+        let mut score = 0;
+        for (i1, r1) in self.iter().enumerate() {
+            for r2 in self[i1 + 1..].iter() {
+                if r1.pos.x.abs_diff(r2.pos.x) <= 1 && r1.pos.y.abs_diff(r2.pos.y) <= 1 {
+                    score += 1;
+                }
+            }
+        }
+        score
+    }
+}
+
+/// Every robot moves on each step, and wraps around the map: after `x_bound` steps a robot is
+/// back on its column, after `y_bound` steps on its row.
+impl Simulation for Map {
+    type State = Vec<Robot>;
+    type Key = Vec<Pos>;
+
+    fn step(&self, robots: &Vec<Robot>) -> Vec<Robot> {
+        let mut robots = robots.clone();
+        robots.iter_mut().for_each(|r| r.move_n_times(self, 1));
+        robots
+    }
+
+    fn key(&self, robots: &Vec<Robot>) -> Vec<Pos> {
+        robots.iter().map(|r| r.pos).collect()
+    }
+
+    fn period(&self) -> Option<usize> {
+        Some(self.bound.x_bound * self.bound.y_bound)
+    }
+}
+
+impl Map {
+    /// Robots come back to their column every `x_bound` seconds and to their row every `y_bound`
+    /// seconds, so the columns are tightest at one offset modulo `x_bound`, the rows at one offset
+    /// modulo `y_bound`, and the picture shows when both happen at once.
+    ///
+    /// Returns the second the picture appears and the robots at that time, unless the robots
+    /// never gather.
+    fn find_picture(&self, robots: &[Robot]) -> Option<(usize, Vec<Robot>)> {
+        let Bound { x_bound, y_bound } = self.bound;
+        let tightest = |period: usize, coord: fn(&Pos) -> usize| {
+            (0..period)
+                .min_by_key(|&secs| spread(robots.iter().map(|r| coord(&r.at(self, secs)))))
+                .unwrap()
+        };
+        let tx = tightest(x_bound, |p| p.x);
+        let ty = tightest(y_bound, |p| p.y);
+        let (secs, _) = crt(&[(tx as i64, x_bound as i64), (ty as i64, y_bound as i64)]).ok()?;
+        let secs = secs as usize;
+
+        let robots: Vec<Robot> = robots
+            .iter()
+            .map(|r| Robot {
+                pos: r.at(self, secs),
+                vel: r.vel,
+            })
+            .collect();
+        is_picture(&robots).then_some((secs, robots))
+    }
+}
+
+/// Scattered robots are rarely next to each other: for 500 of them on the whole map, about a
+/// hundred pairs are. Robots drawing lines have one or two neighbours each.
+fn is_picture(robots: &Vec<Robot>) -> bool {
+    !robots.is_empty() && robots.proximity_score() * 2 >= robots.len()
+}
+
+impl Robot {
+    /// Position after `n` seconds.
+    fn at(&self, map: &Map, n: usize) -> Pos {
+        let mut n_vel = self.vel;
+        n_vel.multiply(n as isize);
+        n_vel.apply_wrap_bounded(&self.pos, &map.bound)
+    }
+
+    fn move_n_times(&mut self, map: &Map, n: usize) {
+        self.pos = self.at(map, n);
+    }
+
+    fn quarter(&self, map: &Map) -> Option<Quarter> {
+        let hx = map.bound.x_bound.div_euclid(2);
+        let hy = map.bound.y_bound.div_euclid(2);
+        match (self.pos.x.cmp(&hx), self.pos.y.cmp(&hy)) {
+            (Ordering::Equal, _) | (_, Ordering::Equal) => None,
+            (Ordering::Greater, Ordering::Greater) => Some(Quarter::BottomRight),
+            (Ordering::Less, Ordering::Greater) => Some(Quarter::BottomLeft),
+            (Ordering::Greater, Ordering::Less) => Some(Quarter::TopRight),
+            (Ordering::Less, Ordering::Less) => Some(Quarter::TopLeft),
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    #[cfg(debug_assertions)]
+    let map = Map {
+        bound: Bound {
+            x_bound: 11,
+            y_bound: 7,
+        },
+    };
+
+    #[cfg(not(debug_assertions))]
+    let map = Map {
+        bound: Bound {
+            x_bound: 101,
+            y_bound: 103,
+        },
+    };
+
+    let mut robots: Vec<Robot> = input.lines().map(Robot::from).collect();
+
+    robots.iter_mut().for_each(|r| r.move_n_times(&map, 100));
+    let mut top_right_f = 0;
+    let mut top_left_f = 0;
+    let mut bottom_right_f = 0;
+    let mut bottom_left_f = 0;
+    robots.iter().for_each(|r| match r.quarter(&map) {
+        None => (),
+        Some(q) => match q {
+            Quarter::BottomLeft => bottom_left_f += 1,
+            Quarter::BottomRight => bottom_right_f += 1,
+            Quarter::TopLeft => top_left_f += 1,
+            Quarter::TopRight => top_right_f += 1,
+        },
+    });
+    Some(top_left_f * top_right_f * bottom_left_f * bottom_right_f)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let map = Map {
+        bound: Bound {
+            x_bound: 101,
+            y_bound: 103,
+        },
+    };
+    let robots: Vec<Robot> = input.lines().map(Robot::from).collect();
+    let (secs, robots) = map.find_picture(&robots)?;
+    artifact::record(format!("picture after {secs}s"), Picture(&map, &robots));
+    Some(secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_robot_movement() {
+        let map = Map {
+            bound: Bound {
+                x_bound: 11,
+                y_bound: 7,
+            },
+        };
+        let mut robot = Robot::from("p=2,4 v=2,-3");
+        assert_eq!(robot.pos.x, 2);
+        assert_eq!(robot.pos.y, 4);
+
+        robot.move_n_times(&map, 1);
+        assert_eq!(robot.pos.x, 4);
+        assert_eq!(robot.pos.y, 1);
+
+        robot.move_n_times(&map, 1);
+        assert_eq!(robot.pos.x, 6);
+        assert_eq!(robot.pos.y, 5);
+
+        robot.move_n_times(&map, 1);
+        assert_eq!(robot.pos.x, 8);
+        assert_eq!(robot.pos.y, 2);
+
+        robot.move_n_times(&map, 1);
+        assert_eq!(robot.pos.x, 10);
+        assert_eq!(robot.pos.y, 6);
+
+        robot.move_n_times(&map, 1);
+        assert_eq!(robot.pos.x, 1);
+        assert_eq!(robot.pos.y, 3);
+
+        let mut robot = Robot::from("p=2,4 v=2,-3");
+        robot.move_n_times(&map, 5);
+        assert_eq!(robot.pos.x, 1);
+        assert_eq!(robot.pos.y, 3);
+    }
+
+    #[test]
+    fn test_robots_cycle() {
+        use advent_of_code::simulation::{brent, nth_state};
+
+        let map = Map {
+            bound: Bound {
+                x_bound: 11,
+                y_bound: 7,
+            },
+        };
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let robots: Vec<Robot> = input.lines().map(Robot::from).collect();
+        let cycle = brent(&map, &robots, 1_000).unwrap();
+        assert_eq!(cycle.prefix, 0);
+        assert_eq!(77 % cycle.period, 0);
+
+        let far = nth_state(&map, &robots, 77 * 1_000_000 + 100);
+        let mut expected = robots.clone();
+        expected.iter_mut().for_each(|r| r.move_n_times(&map, 100));
+        assert_eq!(map.key(&far), map.key(&expected));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(12));
+    }
+
+    // #[test]
+    // fn test_part_two() {
+    //     let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+    //     assert_eq!(result, None);
+    // }
+
+    #[test]
+    fn test_find_picture() {
+        let map = Map {
+            bound: Bound {
+                x_bound: 101,
+                y_bound: 103,
+            },
+        };
+        let secs = 4321;
+        // a filled square among scattered robots, with pseudo-random velocities
+        let mut seed: u64 = 2024;
+        let mut rand = |n: usize| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize % n
+        };
+        let mut targets: Vec<Pos> = (0..400)
+            .map(|i| Pos {
+                x: 40 + i % 20,
+                y: 30 + i / 20,
+            })
+            .collect();
+        for _ in 0..100 {
+            targets.push(Pos {
+                x: rand(101),
+                y: rand(103),
+            });
+        }
+        let robots: Vec<Robot> = targets
+            .into_iter()
+            .map(|target| {
+                let vel = DirVec {
+                    dx: rand(201) as isize - 100,
+                    dy: rand(201) as isize - 100,
+                };
+                let mut back = vel;
+                back.multiply(-(secs as isize));
+                Robot {
+                    pos: back.apply_wrap_bounded(&target, &map.bound),
+                    vel,
+                }
+            })
+            .collect();
+
+        let (found, pictured) = map.find_picture(&robots).unwrap();
+        assert_eq!(found, secs);
+        assert!(Picture(&map, &pictured)
+            .to_string()
+            .contains(&"1".repeat(20)));
+        assert!(map.find_picture(&robots[400..]).is_none());
+    }
+}
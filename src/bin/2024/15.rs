@@ -0,0 +1,244 @@
+use std::fmt::{Display, Write};
+
+use advent_of_code::{Dir, FromChar, Pos};
+
+advent_of_code::solution!(2024, 15);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Robot,
+    Box,
+}
+
+/// Something that moves as a whole, over one or more cells.
+#[derive(Debug, Clone)]
+struct Object {
+    kind: Kind,
+    /// From left to right.
+    cells: Vec<Pos>,
+}
+
+/// An object would hit a wall.
+#[derive(Debug)]
+struct Blocked;
+
+#[derive(Debug, Clone)]
+struct Warehouse {
+    walls: Vec<Vec<bool>>,
+    objects: Vec<Object>,
+    /// Object covering each cell, if any.
+    occupied: Vec<Vec<Option<usize>>>,
+    robot: usize,
+}
+
+impl Warehouse {
+    /// Reads the map, each tile being `width` cells wide. Boxes are as wide as tiles, the robot
+    /// stays on a single cell.
+    fn parse(map: &str, width: usize) -> Self {
+        let mut walls = Vec::new();
+        let mut objects = Vec::new();
+        let mut robot = None;
+        for (y, l) in map.lines().enumerate() {
+            let mut row = Vec::new();
+            for (x, c) in l.chars().enumerate() {
+                let pos = Pos { x: x * width, y };
+                row.extend((0..width).map(|_| c == '#'));
+                match c {
+                    '#' | '.' => (),
+                    'O' => objects.push(Object {
+                        kind: Kind::Box,
+                        cells: (0..width).map(|i| Pos { x: pos.x + i, y }).collect(),
+                    }),
+                    '@' => {
+                        robot = Some(objects.len());
+                        objects.push(Object {
+                            kind: Kind::Robot,
+                            cells: vec![pos],
+                        });
+                    }
+                    other => panic!("unexpected {other}"),
+                }
+            }
+            walls.push(row);
+        }
+
+        let mut occupied: Vec<Vec<Option<usize>>> =
+            walls.iter().map(|row| vec![None; row.len()]).collect();
+        for (id, object) in objects.iter().enumerate() {
+            for cell in &object.cells {
+                occupied[cell.y][cell.x] = Some(id);
+            }
+        }
+        Self {
+            walls,
+            objects,
+            occupied,
+            robot: robot.unwrap(),
+        }
+    }
+
+    /// Objects moved by pushing `object` towards `dir`, `object` included: everything in front
+    /// of a moved object moves too.
+    fn pushed(&self, object: usize, dir: Dir) -> Result<Vec<usize>, Blocked> {
+        let mut moved = vec![object];
+        let mut i = 0;
+        while let Some(&id) = moved.get(i) {
+            i += 1;
+            for cell in &self.objects[id].cells {
+                let next = dir.apply(cell).ok_or(Blocked)?;
+                if self.walls[next.y][next.x] {
+                    return Err(Blocked);
+                }
+                match self.occupied[next.y][next.x] {
+                    Some(other) if other != id && !moved.contains(&other) => moved.push(other),
+                    _ => (),
+                }
+            }
+        }
+        Ok(moved)
+    }
+
+    /// Moves `object` and everything it pushes one cell towards `dir`, or nothing if blocked.
+    fn push(&mut self, object: usize, dir: Dir) -> Result<(), Blocked> {
+        let moved = self.pushed(object, dir)?;
+        for &id in &moved {
+            for cell in &self.objects[id].cells {
+                self.occupied[cell.y][cell.x] = None;
+            }
+        }
+        for &id in &moved {
+            for cell in self.objects[id].cells.iter_mut() {
+                *cell = dir.apply(cell).unwrap();
+                self.occupied[cell.y][cell.x] = Some(id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the robot moved.
+    fn move_robot(&mut self, dir: Dir) -> bool {
+        self.push(self.robot, dir).is_ok()
+    }
+
+    /// The warehouse after each move.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn steps(self, moves: Vec<Dir>) -> impl Iterator<Item = Warehouse> {
+        moves.into_iter().scan(self, |warehouse, dir| {
+            warehouse.move_robot(dir);
+            Some(warehouse.clone())
+        })
+    }
+
+    fn box_gps(&self) -> usize {
+        self.objects
+            .iter()
+            .filter(|o| o.kind == Kind::Box)
+            .map(|o| 100 * o.cells[0].y + o.cells[0].x)
+            .sum()
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.occupied.iter().enumerate() {
+            for (x, id) in row.iter().enumerate() {
+                let c = match id.map(|id| &self.objects[id]) {
+                    None if self.walls[y][x] => '#',
+                    None => '.',
+                    Some(Object {
+                        kind: Kind::Robot, ..
+                    }) => '@',
+                    Some(Object { cells, .. }) if cells.len() == 1 => 'O',
+                    Some(Object { cells, .. }) if cells[0].x == x => '[',
+                    Some(Object { cells, .. }) if cells[cells.len() - 1].x == x => ']',
+                    Some(_) => '=',
+                };
+                f.write_char(c)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_moves(moves: &str) -> Vec<Dir> {
+    moves
+        .replace('\n', "")
+        .chars()
+        .map(Dir::from_char)
+        .collect()
+}
+
+fn gps_after_moves(input: &str, width: usize) -> usize {
+    let (map, moves) = input.split_once("\n\n").unwrap();
+    let mut warehouse = Warehouse::parse(map, width);
+    for dir in parse_moves(moves) {
+        warehouse.move_robot(dir);
+    }
+    warehouse.box_gps()
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(gps_after_moves(input, 1))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(gps_after_moves(input, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_small_ex() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 0,
+        ));
+        assert_eq!(result, Some(2028));
+    }
+
+    #[test]
+    fn test_part_one_bigger_ex() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 1,
+        ));
+        assert_eq!(result, Some(10092));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 1,
+        ));
+        assert_eq!(result, Some(9021));
+    }
+
+    #[test]
+    fn test_steps() {
+        let map = "#######\n#...#.#\n#.....#\n#..OO@#\n#..O..#\n#.....#\n#######";
+        let moves = parse_moves("<vv<<^^<<^^");
+        let states: Vec<Warehouse> = Warehouse::parse(map, 2).steps(moves).collect();
+        assert_eq!(states.len(), 11);
+        assert_eq!(
+            states[0].to_string(),
+            "##############\n\
+             ##......##..##\n\
+             ##..........##\n\
+             ##...[][]@..##\n\
+             ##....[]....##\n\
+             ##..........##\n\
+             ##############\n"
+        );
+        assert_eq!(
+            states[10].to_string(),
+            "##############\n\
+             ##...[].##..##\n\
+             ##...@.[]...##\n\
+             ##....[]....##\n\
+             ##..........##\n\
+             ##..........##\n\
+             ##############\n"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap},
+};
+
+use advent_of_code::{Dir, Pos};
+
+advent_of_code::solution!(2024, 16);
+
+const FORWARD_COST: usize = 1;
+const ROTATE_COST: usize = 1_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Empty,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ReindeerState {
+    reindeer: Pos,
+    reindeer_dir: Dir,
+}
+
+struct Maze {
+    map: Vec<Vec<Tile>>,
+    target: Pos,
+    init_state: ReindeerState,
+}
+
+impl From<&str> for Maze {
+    fn from(value: &str) -> Self {
+        let mut reindeer = None;
+        let mut target = None;
+        let map = value
+            .lines()
+            .enumerate()
+            .map(|(y, l)| {
+                l.chars()
+                    .enumerate()
+                    .map(|(x, c)| match c {
+                        '.' => Tile::Empty,
+                        '#' => Tile::Wall,
+                        'S' => {
+                            reindeer = Some(Pos { x, y });
+                            Tile::Empty
+                        }
+                        'E' => {
+                            target = Some(Pos { x, y });
+                            Tile::Empty
+                        }
+                        _ => panic!("Unsupported char {c}"),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            map,
+            target: target.unwrap(),
+            init_state: ReindeerState {
+                reindeer: reindeer.unwrap(),
+                reindeer_dir: Dir::Right, /* East by default */
+            },
+        }
+    }
+}
+
+impl Maze {
+    fn get(&self, pos: Pos) -> Tile {
+        self.map[pos.y][pos.x]
+    }
+
+    fn width(&self) -> usize {
+        self.map.first().map_or(0, |line| line.len())
+    }
+
+    /// Dense index of a state, for the distance fields.
+    fn index(&self, state: ReindeerState) -> usize {
+        (state.reindeer.y * self.width() + state.reindeer.x) * 4 + state.reindeer_dir as usize
+    }
+
+    fn state(&self, index: usize) -> ReindeerState {
+        let tile = index / 4;
+        ReindeerState {
+            reindeer: Pos {
+                x: tile % self.width(),
+                y: tile / self.width(),
+            },
+            reindeer_dir: Dir::all()[index % 4],
+        }
+    }
+
+    /// States reachable in one move from `state` (or leading to `state` when `backward`),
+    /// with the cost of the move. The maze is walled, so moves never leave the map.
+    fn moves(
+        &self,
+        state: ReindeerState,
+        backward: bool,
+    ) -> impl Iterator<Item = (ReindeerState, usize)> {
+        let step_dir = if backward {
+            state.reindeer_dir.opposite()
+        } else {
+            state.reindeer_dir
+        };
+        let forward = step_dir
+            .apply(&state.reindeer)
+            .filter(|&pos| self.get(pos) == Tile::Empty)
+            .map(|reindeer| (ReindeerState { reindeer, ..state }, FORWARD_COST));
+        let rotations = [
+            state.reindeer_dir.rotate_left(),
+            state.reindeer_dir.rotate_right(),
+        ]
+        .map(|reindeer_dir| {
+            (
+                ReindeerState {
+                    reindeer_dir,
+                    ..state
+                },
+                ROTATE_COST,
+            )
+        });
+        forward.into_iter().chain(rotations)
+    }
+
+    /// Dijkstra from every state of `starts`: lowest score to reach each state (or to reach
+    /// one of `starts` from it, when `backward`), `usize::MAX` if unreachable.
+    fn distances(&self, starts: &[ReindeerState], backward: bool) -> Vec<usize> {
+        let mut dist = vec![usize::MAX; self.map.len() * self.width() * 4];
+        let mut heap = BinaryHeap::new();
+        for &start in starts {
+            dist[self.index(start)] = 0;
+            heap.push(Reverse((0, self.index(start))));
+        }
+
+        while let Some(Reverse((score, idx))) = heap.pop() {
+            if score > dist[idx] {
+                continue;
+            }
+            for (next, cost) in self.moves(self.state(idx), backward) {
+                let next_idx = self.index(next);
+                let next_score = score + cost;
+                if next_score < dist[next_idx] {
+                    dist[next_idx] = next_score;
+                    heap.push(Reverse((next_score, next_idx)));
+                }
+            }
+        }
+        dist
+    }
+
+    fn target_states(&self) -> [ReindeerState; 4] {
+        Dir::all().map(|reindeer_dir| ReindeerState {
+            reindeer: self.target,
+            reindeer_dir,
+        })
+    }
+
+    /// Lowest score to reach the target, given the scores to reach every state, or `None` if
+    /// the target cannot be reached.
+    fn best_score(&self, from_start: &[usize]) -> Option<usize> {
+        self.target_states()
+            .iter()
+            .map(|&state| from_start[self.index(state)])
+            .min()
+            .filter(|&score| score != usize::MAX)
+    }
+
+    /// Tiles on at least one best path. A state is on a best path when the score to reach it
+    /// plus the score from it to the target is the best score.
+    fn best_tiles(&self) -> Option<BTreeSet<Pos>> {
+        let from_start = self.distances(&[self.init_state], false);
+        let score = self.best_score(&from_start)?;
+        let to_target = self.distances(&self.target_states(), true);
+
+        let tiles: BTreeSet<Pos> = from_start
+            .iter()
+            .zip(&to_target)
+            .enumerate()
+            .filter(|(_, (&f, &b))| f != usize::MAX && b != usize::MAX && f + b == score)
+            .map(|(idx, _)| self.state(idx).reindeer)
+            .collect();
+        Some(tiles)
+    }
+
+    /// Debugging aid: the maze with `path` tiles drawn as `O`.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn render_with_path(&self, path: &BTreeSet<Pos>) -> String {
+        let mut out = String::new();
+        for (y, line) in self.map.iter().enumerate() {
+            for (x, t) in line.iter().enumerate() {
+                let pos = Pos { x, y };
+                if path.contains(&pos) {
+                    assert!(self.get(pos) == Tile::Empty);
+                    out += "O"
+                } else {
+                    match t {
+                        Tile::Empty => out += ".",
+                        Tile::Wall => out += "#",
+                    }
+                }
+            }
+            out += "\n";
+        }
+        out
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let maze = Maze::from(input);
+    maze.best_score(&maze.distances(&[maze.init_state], false))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let maze = Maze::from(input);
+    Some(maze.best_tiles()?.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_0() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 0,
+        ));
+        assert_eq!(result, Some(7036));
+    }
+
+    #[test]
+    fn test_part_one_1() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 1,
+        ));
+        assert_eq!(result, Some(11048));
+    }
+
+    #[test]
+    fn test_part_two_0() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 0,
+        ));
+        assert_eq!(result, Some(45));
+    }
+
+    #[test]
+    fn test_part_two_1() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 1,
+        ));
+        assert_eq!(result, Some(64));
+    }
+
+    #[test]
+    fn test_render_best_paths() {
+        let maze =
+            Maze::from(advent_of_code::template::read_file_part("examples", YEAR, DAY, 0).as_str());
+        let rendered = maze.render_with_path(&maze.best_tiles().unwrap());
+        let expected = "\
+###############
+#.......#....O#
+#.#.###.#.###O#
+#.....#.#...#O#
+#.###.#####.#O#
+#.#.#.......#O#
+#.#.#####.###O#
+#..OOOOOOOOO#O#
+###O#O#####O#O#
+#OOO#O....#O#O#
+#O#O#O###.#O#O#
+#OOOOO#...#O#O#
+#O###.#.#.#O#O#
+#O..#.....#OOO#
+###############
+";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_unreachable_end() {
+        let input = "#####\n#S#E#\n#####\n";
+        assert_eq!(part_one(input), None);
+        assert_eq!(part_two(input), None);
+    }
+}
@@ -0,0 +1,41 @@
+use advent_of_code::vm::Machine;
+use itertools::Itertools;
+
+advent_of_code::solution!(2024, 17);
+
+pub fn part_one(input: &str) -> Option<String> {
+    let mut machine = Machine::from(input);
+    let out = machine.run().ok()?;
+    Some(out.into_iter().map(|tb| tb.to_string()).join(","))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    match Machine::from(input).find_quine() {
+        Ok(a) => Some(a),
+        Err(err) => {
+            eprintln!("{err}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 1,
+        ));
+        assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 2,
+        ));
+        assert_eq!(result, Some(117440));
+    }
+}
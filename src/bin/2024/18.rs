@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, VecDeque};
 
 use advent_of_code::{Bound, Dir, Pos};
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(2024, 18);
 
 #[cfg(test)]
 const MEMORY_GRID_SIZE: usize = 6 + 1;
@@ -175,13 +175,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(22));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some("6,1".to_owned()));
     }
 }
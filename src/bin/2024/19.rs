@@ -0,0 +1,59 @@
+use advent_of_code::trie::Trie;
+
+advent_of_code::solution!(2024, 19);
+
+fn parse(input: &str) -> (Trie, Vec<&str>) {
+    let (towels, designs) = input.split_once("\n\n").unwrap();
+    let towels = towels.split(", ").map(str::trim).collect();
+    let designs = designs
+        .lines()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .collect();
+    (towels, designs)
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let (towels, designs) = parse(input);
+    Some(designs.iter().filter(|d| towels.can_segment(d)).count())
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let (towels, designs) = parse(input);
+    let total = designs.iter().try_fold(0u64, |total, d| {
+        let arrangements = towels.count_segmentations(d).ok()?;
+        total.checked_add(arrangements)
+    });
+    match total.and_then(|total| usize::try_from(total).ok()) {
+        Some(total) => Some(total),
+        None => {
+            eprintln!("too many arrangements to count");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(6));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(16));
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let (towels, _) = parse(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(towels.count_segmentations("rrbgbr"), Ok(6));
+        let arrangements: Vec<Vec<&str>> = towels.segmentations("bwurrg").collect();
+        assert_eq!(arrangements, vec![vec!["bwu", "r", "r", "g"]]);
+    }
+}
@@ -0,0 +1,113 @@
+use advent_of_code::{
+    distance::{cheats, DistanceField},
+    Pos,
+};
+
+advent_of_code::solution!(2024, 20);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Empty,
+    Start,
+    End,
+}
+
+impl From<char> for Tile {
+    fn from(c: char) -> Self {
+        match c {
+            '#' => Self::Wall,
+            '.' => Self::Empty,
+            'S' => Self::Start,
+            'E' => Self::End,
+            _ => panic!(),
+        }
+    }
+}
+
+struct Map {
+    map: Vec<Vec<Tile>>,
+    start: Pos,
+    end: Pos,
+}
+
+impl From<&str> for Map {
+    fn from(value: &str) -> Self {
+        let mut start = None;
+        let mut end = None;
+        let map = value
+            .lines()
+            .enumerate()
+            .map(|(y, l)| {
+                l.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        let t = Tile::from(c);
+                        match t {
+                            Tile::Start => start = Some(Pos { x, y }),
+                            Tile::End => end = Some(Pos { x, y }),
+                            _ => (),
+                        }
+                        t
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            map,
+            start: start.unwrap(),
+            end: end.unwrap(),
+        }
+    }
+}
+
+impl Map {
+    fn distances_from(&self, source: Pos) -> DistanceField {
+        DistanceField::bfs(&self.map, source, |&t| t != Tile::Wall)
+    }
+
+    /// Cheats of at most `max_length` steps saving at least `min_saving` steps.
+    fn count_cheats(&self, max_length: usize, min_saving: usize) -> usize {
+        let from_start = self.distances_from(self.start);
+        let to_end = self.distances_from(self.end);
+        cheats(&from_start, &to_end, max_length)
+            .filter(|cheat| cheat.saving >= min_saving)
+            .count()
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let map = Map::from(input);
+    Some(map.count_cheats(2, 100))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let map = Map::from(input);
+    Some(map.count_cheats(20, 100))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(0)); /* 0 because of > 100 ps condition for full data */
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(0)); /* 0 because of > 100 ps condition for full data */
+    }
+
+    #[test]
+    fn test_count_cheats() {
+        let map = Map::from(advent_of_code::template::read_file("examples", YEAR, DAY).as_str());
+        assert_eq!(map.count_cheats(2, 1), 44);
+        assert_eq!(map.count_cheats(2, 64), 1);
+        assert_eq!(map.count_cheats(20, 50), 285);
+        assert_eq!(map.count_cheats(20, 76), 3);
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::{HashMap, VecDeque};
+
+use advent_of_code::{memo::Memo, Dir, FromChar, Pos};
+
+advent_of_code::solution!(2024, 21);
+
+/// The door keypad, a space marking the gap.
+const NUMERIC: &str = "789\n456\n123\n 0A";
+/// The keypad of the robots, and of the human at the end of the chain.
+const DIRECTIONAL: &str = " ^A\n<v>";
+
+/// Keys laid out on a grid. An arm pointing at a cell without key panics.
+#[derive(Debug, Clone)]
+struct Keypad {
+    keys: HashMap<char, Pos>,
+    cells: Vec<Vec<Option<char>>>,
+}
+
+impl From<&str> for Keypad {
+    fn from(value: &str) -> Self {
+        let cells: Vec<Vec<Option<char>>> = value
+            .lines()
+            .map(|l| l.chars().map(|c| (c != ' ').then_some(c)).collect())
+            .collect();
+        let keys = cells
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(move |(x, c)| c.map(|c| (c, Pos { x, y })))
+            })
+            .collect();
+        Self { keys, cells }
+    }
+}
+
+impl Keypad {
+    fn pos(&self, key: char) -> Pos {
+        *self
+            .keys
+            .get(&key)
+            .unwrap_or_else(|| panic!("no key '{key}' on keypad"))
+    }
+
+    fn key_at(&self, pos: Pos) -> Option<char> {
+        *self.cells.get(pos.y)?.get(pos.x)?
+    }
+
+    /// Presses moving the arm from `from` to `to` then pressing `to`, that never point at a gap.
+    ///
+    /// Turning costs presses on the next keypad, so when a way with a single turn (or none)
+    /// avoids the gaps, only those can be the cheapest. Otherwise every shortest way around the
+    /// gaps is a candidate.
+    fn paths(&self, from: char, to: char) -> Vec<String> {
+        let (from, to) = (self.pos(from), self.pos(to));
+        let x_dir = if to.x < from.x { Dir::Left } else { Dir::Right };
+        let y_dir = if to.y < from.y { Dir::Up } else { Dir::Down };
+        let horizontal = (x_dir, from.x.abs_diff(to.x));
+        let vertical = (y_dir, from.y.abs_diff(to.y));
+
+        let mut paths: Vec<String> = Vec::new();
+        for legs in [[horizontal, vertical], [vertical, horizontal]] {
+            let mut pos = from;
+            let mut path = String::new();
+            let mut avoids_gaps = true;
+            for (dir, n) in legs {
+                for _ in 0..n {
+                    pos = dir.apply(&pos).unwrap();
+                    avoids_gaps &= self.key_at(pos).is_some();
+                    path.push(dir.to_char());
+                }
+            }
+            path.push('A');
+            if avoids_gaps && !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        if paths.is_empty() {
+            paths = self.detours(from, to);
+        }
+        paths
+    }
+
+    /// Every shortest way from `from` to `to` that only points at keys, then pressing `to`.
+    fn detours(&self, from: Pos, to: Pos) -> Vec<String> {
+        // steps left to `to`, from every key that can reach it
+        let mut left = HashMap::from([(to, 0)]);
+        let mut queue = VecDeque::from([to]);
+        while let Some(pos) = queue.pop_front() {
+            for dir in Dir::all() {
+                let Some(next) = dir.apply(&pos) else {
+                    continue;
+                };
+                if self.key_at(next).is_some() && !left.contains_key(&next) {
+                    left.insert(next, left[&pos] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut paths = Vec::new();
+        let mut stack = vec![(from, String::new())];
+        while let Some((pos, path)) = stack.pop() {
+            let Some(&steps) = left.get(&pos) else {
+                continue;
+            };
+            if steps == 0 {
+                paths.push(path + "A");
+                continue;
+            }
+            for dir in Dir::all() {
+                if let Some(next) = dir.apply(&pos) {
+                    if left.get(&next) == Some(&(steps - 1)) {
+                        stack.push((next, format!("{path}{}", dir.to_char())));
+                    }
+                }
+            }
+        }
+        paths
+    }
+}
+
+/// Keypads where each one is typed on by a robot arm, steered from the next keypad. The last
+/// keypad is pressed by hand.
+struct KeypadChain {
+    keypads: Vec<Keypad>,
+    /// Fewest presses by hand for the arm over a keypad to move between two keys and press the
+    /// second one.
+    memo: Memo<(usize, char, char), usize>,
+}
+
+impl KeypadChain {
+    fn new(keypads: Vec<Keypad>) -> Self {
+        assert!(!keypads.is_empty());
+        Self {
+            keypads,
+            memo: Memo::new(),
+        }
+    }
+
+    /// `door` at the start of the chain, then `depth` robots steering each other through
+    /// directional keypads, then the human.
+    fn with_robots(door: &str, depth: usize) -> Self {
+        let mut keypads = vec![Keypad::from(door)];
+        keypads.extend((0..=depth).map(|_| Keypad::from(DIRECTIONAL)));
+        Self::new(keypads)
+    }
+
+    fn is_by_hand(&self, layer: usize) -> bool {
+        layer + 1 == self.keypads.len()
+    }
+
+    /// Fewest presses by hand to type `keys` on keypad `layer`, every arm starting and ending on
+    /// `A`.
+    fn presses(&mut self, layer: usize, keys: &str) -> usize {
+        if self.is_by_hand(layer) {
+            return keys.chars().count();
+        }
+        let mut from = 'A';
+        let mut total = 0;
+        for to in keys.chars() {
+            total += self.move_and_press(layer, from, to);
+            from = to;
+        }
+        total
+    }
+
+    fn move_and_press(&mut self, layer: usize, from: char, to: char) -> usize {
+        if let Some(presses) = self.memo.lookup(&(layer, from, to)) {
+            return presses;
+        }
+        let presses = self.keypads[layer]
+            .paths(from, to)
+            .iter()
+            .map(|path| self.presses(layer + 1, path))
+            .min()
+            .unwrap_or_else(|| panic!("no way from '{from}' to '{to}' on keypad {layer}"));
+        self.memo.insert((layer, from, to), presses);
+        presses
+    }
+
+    /// One of the shortest sequences of presses by hand typing `keys` on keypad `layer`. Its
+    /// length grows exponentially with the depth: only for small chains.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn sequence(&mut self, layer: usize, keys: &str) -> String {
+        if self.is_by_hand(layer) {
+            return keys.to_string();
+        }
+        let mut from = 'A';
+        let mut sequence = String::new();
+        for to in keys.chars() {
+            let best = self.keypads[layer]
+                .paths(from, to)
+                .into_iter()
+                .min_by_key(|path| self.presses(layer + 1, path))
+                .unwrap();
+            sequence += &self.sequence(layer + 1, &best);
+            from = to;
+        }
+        sequence
+    }
+
+    /// Replays presses by hand through the chain, returning what is typed on the first keypad,
+    /// or `None` if an arm points at a gap or off its keypad.
+    #[cfg_attr(not(test), allow(dead_code))]
+    fn simulate(&self, presses: &str) -> Option<String> {
+        let mut arms: Vec<Pos> = self.keypads[..self.keypads.len() - 1]
+            .iter()
+            .map(|keypad| keypad.pos('A'))
+            .collect();
+        let mut typed = String::new();
+        for key in presses.chars() {
+            // the key pressed on a keypad steers the arm over the previous one
+            let mut layer = self.keypads.len() - 1;
+            let mut key = key;
+            loop {
+                if layer == 0 {
+                    typed.push(key);
+                    break;
+                }
+                let arm = &mut arms[layer - 1];
+                if key == 'A' {
+                    key = self.keypads[layer - 1].key_at(*arm)?;
+                    layer -= 1;
+                } else {
+                    *arm = Dir::from_char(key).apply(arm)?;
+                    self.keypads[layer - 1].key_at(*arm)?;
+                    break;
+                }
+            }
+        }
+        Some(typed)
+    }
+}
+
+fn complexity(input: &str, depth: usize) -> usize {
+    let mut chain = KeypadChain::with_robots(NUMERIC, depth);
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|code| {
+            let num_code: usize = code[..(code.len() - 1)].parse().unwrap();
+            num_code * chain.presses(0, code)
+        })
+        .sum()
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(complexity(input, 2))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(complexity(input, 25))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(126384));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(154115708116294));
+    }
+
+    #[test]
+    fn test_paths_avoid_gap() {
+        let numeric = Keypad::from(NUMERIC);
+        assert_eq!(numeric.paths('A', '1'), vec!["^<<A"]);
+        assert_eq!(numeric.paths('7', '0'), vec![">vvvA"]);
+        assert_eq!(numeric.paths('2', '9'), vec![">^^A", "^^>A"]);
+        assert_eq!(numeric.paths('5', '5'), vec!["A"]);
+    }
+
+    #[test]
+    fn test_sequence_replays_to_code() {
+        let mut chain = KeypadChain::with_robots(NUMERIC, 2);
+        for (code, len) in [("029A", 68), ("980A", 60), ("179A", 68), ("379A", 64)] {
+            let sequence = chain.sequence(0, code);
+            assert_eq!(sequence.len(), len);
+            assert_eq!(chain.presses(0, code), len);
+            assert_eq!(chain.simulate(&sequence).as_deref(), Some(code));
+        }
+        assert_eq!(chain.simulate("<<A"), None);
+    }
+
+    #[test]
+    fn test_other_layouts() {
+        // a single robot on a keypad with its gap in the middle
+        let mut chain =
+            KeypadChain::new(vec![Keypad::from("12\n A\n34"), Keypad::from(DIRECTIONAL)]);
+        let sequence = chain.sequence(0, "41");
+        assert_eq!(chain.simulate(&sequence).as_deref(), Some("41"));
+        assert_eq!(sequence.len(), chain.presses(0, "41"));
+
+        // both single-turn ways from 1 to 3 cross the gap
+        let keypad = Keypad::from("12\n A\n34");
+        assert_eq!(keypad.paths('1', '3'), vec![">vv<A"]);
+        let sequence = chain.sequence(0, "13");
+        assert_eq!(chain.simulate(&sequence).as_deref(), Some("13"));
+        assert_eq!(sequence.len(), chain.presses(0, "13"));
+    }
+}
@@ -0,0 +1,322 @@
+#![cfg_attr(test, feature(int_roundings))]
+
+use std::thread;
+
+advent_of_code::solution!(2024, 22);
+
+const PRUNE_MASK: u32 = 16777216 - 1;
+
+/// - Calculate the result of multiplying the secret number by 64. Then, mix this result into the secret number. Finally, prune the secret number.
+/// - Calculate the result of dividing the secret number by 32. Round the result down to the nearest integer. Then, mix this result into the secret number. Finally, prune the secret number.
+/// - Calculate the result of multiplying the secret number by 2048. Then, mix this result into the secret number. Finally, prune the secret number.
+fn next_secret(secret: u32) -> u32 {
+    let secret = (secret ^ (secret << 6)) & PRUNE_MASK;
+    let secret = secret ^ (secret >> 5);
+    (secret ^ (secret << 11)) & PRUNE_MASK
+}
+
+/// Buyers simulated in lockstep.
+const LANES: usize = 8;
+
+/// Advances one secret per buyer; the loop has no dependency between lanes, so it vectorizes.
+fn next_secrets(secrets: &mut [u32; LANES]) {
+    for secret in secrets.iter_mut() {
+        *secret = next_secret(*secret);
+    }
+}
+
+/// Groups seeds by [`LANES`], padding the last group with zeros (0 is a fixed point of the
+/// generator, with a price of 0).
+fn batches(seeds: &[u32]) -> impl Iterator<Item = [u32; LANES]> + '_ {
+    seeds.chunks(LANES).map(|chunk| {
+        let mut batch = [0; LANES];
+        batch[..chunk.len()].copy_from_slice(chunk);
+        batch
+    })
+}
+
+fn parse_seeds(input: &str) -> Vec<u32> {
+    input
+        .lines()
+        .filter(|&l| !l.is_empty())
+        .map(|l| l.parse().unwrap())
+        .collect()
+}
+
+/// Price changes are in -9..=9, so a sequence of four is a number in base 19.
+const SEQUENCES: usize = 19 * 19 * 19 * 19;
+
+/// Total price per sequence of four changes, over every buyer.
+struct SequenceScores {
+    totals: Vec<u32>,
+    /// Last buyer (stamp) that sold on each sequence; a buyer only sells the first time.
+    seen: Vec<u32>,
+    next_stamp: u32,
+}
+
+impl SequenceScores {
+    fn new() -> Self {
+        Self {
+            totals: vec![0; SEQUENCES],
+            seen: vec![0; SEQUENCES],
+            next_stamp: 1,
+        }
+    }
+
+    fn add_buyers(&mut self, seeds: [u32; LANES], n: usize) {
+        let stamps: [u32; LANES] = std::array::from_fn(|lane| self.next_stamp + lane as u32);
+        self.next_stamp += LANES as u32;
+
+        let mut secrets = seeds;
+        let mut prices = secrets.map(|secret| secret % 10);
+        let mut sequences = [0; LANES];
+        for i in 0..n {
+            next_secrets(&mut secrets);
+            for lane in 0..LANES {
+                let price = secrets[lane] % 10;
+                let change = (price + 9 - prices[lane]) as usize;
+                sequences[lane] = (sequences[lane] * 19 + change) % SEQUENCES;
+                prices[lane] = price;
+
+                let seq = sequences[lane];
+                if i >= 3 && self.seen[seq] != stamps[lane] {
+                    self.seen[seq] = stamps[lane];
+                    self.totals[seq] += price;
+                }
+            }
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (total, other) in self.totals.iter_mut().zip(&other.totals) {
+            *total += other;
+        }
+    }
+
+    fn best(&self) -> u32 {
+        self.totals.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// Best total price over `n` prices per buyer, with buyers sharded across threads.
+fn best_total(seeds: &[u32], n: usize) -> u32 {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let shard_len = seeds
+        .len()
+        .div_ceil(threads)
+        .next_multiple_of(LANES)
+        .max(LANES);
+    thread::scope(|scope| {
+        let shards: Vec<_> = seeds
+            .chunks(shard_len)
+            .map(|shard| {
+                scope.spawn(move || {
+                    let mut scores = SequenceScores::new();
+                    batches(shard).for_each(|batch| scores.add_buyers(batch, n));
+                    scores
+                })
+            })
+            .collect();
+        let mut scores = SequenceScores::new();
+        for shard in shards {
+            scores.merge(&shard.join().unwrap());
+        }
+        scores.best()
+    })
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let seeds = parse_seeds(input);
+    Some(
+        batches(&seeds)
+            .map(|mut secrets| {
+                for _ in 0..2000 {
+                    next_secrets(&mut secrets);
+                }
+                secrets.iter().map(|&secret| secret as usize).sum::<usize>()
+            })
+            .sum(),
+    )
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let seeds = parse_seeds(input);
+    Some(best_total(&seeds, 2000) as usize)
+}
+
+/// The first implementation, one `HashMap` per buyer, kept to check the dense one against.
+#[cfg(test)]
+mod hash_maps {
+    use std::{
+        collections::{HashMap, HashSet},
+        ops::Mul,
+    };
+
+    pub trait MixPrune {
+        fn mix(&self, val: Self) -> Self;
+        fn prune(&self) -> Self;
+    }
+
+    impl MixPrune for usize {
+        fn mix(&self, val: Self) -> Self {
+            *self ^ val
+        }
+        fn prune(&self) -> Self {
+            *self % 16777216
+        }
+    }
+
+    pub struct PseudoRandom {
+        _secret: usize,
+    }
+
+    impl PseudoRandom {
+        pub fn new(seed: usize) -> Self {
+            Self { _secret: seed }
+        }
+
+        fn _derive(&mut self) {
+            let mut state = self._secret;
+            state = state.mul(64).mix(state).prune();
+            state = state.div_floor(32).mix(state).prune();
+            state = state.mul(2048).mix(state).prune();
+            self._secret = state;
+        }
+
+        pub fn get(&mut self) -> usize {
+            self._derive();
+            self._secret
+        }
+
+        fn get_price(&mut self) -> u8 {
+            let n = self.get();
+            (n % 10) as u8
+        }
+
+        fn sequences_to_price(&mut self, n: usize) -> HashMap<[i8; 4], u8> {
+            assert!(n >= 4);
+            let mut max = (self._secret % 10) as u8;
+            let mut previous = max;
+            let mut curr_seq = [0, 0, 0, 0];
+            for change in &mut curr_seq {
+                let p = self.get_price();
+                *change = (p as i8).checked_sub(previous as i8).unwrap();
+                previous = p;
+                if p > max {
+                    max = p;
+                }
+            }
+            let mut sequences = HashMap::new();
+            sequences.insert(curr_seq, previous);
+            for _ in 4..n {
+                let p = self.get_price();
+                curr_seq.copy_within(1..4, 0);
+                curr_seq[3] = (p as i8).checked_sub(previous as i8).unwrap();
+                if p > max {
+                    max = p;
+                }
+                sequences.entry(curr_seq).or_insert(p);
+                previous = p;
+            }
+            sequences
+        }
+    }
+
+    fn best(seq_to_price_maps: Vec<HashMap<[i8; 4], u8>>) -> usize {
+        let mut tested_seq = HashSet::new();
+        let mut max = 0;
+        for seq_to_price_m in &seq_to_price_maps {
+            for seq in seq_to_price_m.keys() {
+                if !tested_seq.insert(seq) {
+                    // already tested this seq
+                    continue;
+                }
+                let mut total = 0;
+                for map in &seq_to_price_maps {
+                    if let Some(p) = map.get(seq) {
+                        total += *p as usize;
+                    }
+                }
+                if total > max {
+                    max = total;
+                }
+            }
+        }
+        max
+    }
+
+    pub fn part_two(input: &str) -> usize {
+        let seq_to_price_maps = input
+            .lines()
+            .filter(|&l| !l.is_empty())
+            .map(|l| PseudoRandom::new(l.parse().unwrap()))
+            .map(|mut pr| pr.sequences_to_price(2000))
+            .collect();
+        best(seq_to_price_maps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        hash_maps::{self, MixPrune, PseudoRandom},
+        *,
+    };
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 1,
+        ));
+        assert_eq!(result, Some(37327623));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 2,
+        ));
+        assert_eq!(result, Some(23));
+    }
+
+    #[test]
+    fn test_mix_prune_usize() {
+        assert_eq!(42.mix(15), 37);
+        assert_eq!(100_000_000.prune(), 16113920);
+    }
+
+    #[test]
+    fn test_pseudo_random() {
+        let mut pseudo_random = PseudoRandom::new(123);
+        let expected_numbers = [
+            15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+            5908254,
+        ];
+
+        for expected in expected_numbers {
+            assert_eq!(expected, pseudo_random.get());
+        }
+    }
+
+    #[test]
+    fn test_next_secret_matches_pseudo_random() {
+        let mut pseudo_random = PseudoRandom::new(123);
+        let mut secrets = [123, 1, 10, 100, 2024, 0, 7, 16777215];
+        let mut reference = secrets.map(|seed| PseudoRandom::new(seed as usize));
+        for _ in 0..100 {
+            next_secrets(&mut secrets);
+            for (secret, pr) in secrets.iter().zip(reference.iter_mut()) {
+                assert_eq!(*secret as usize, pr.get());
+            }
+        }
+        assert_eq!(next_secret(123) as usize, pseudo_random.get());
+    }
+
+    #[test]
+    fn test_dense_scores_match_hash_maps() {
+        for part in [1, 2] {
+            let input = advent_of_code::template::read_file_part("examples", YEAR, DAY, part);
+            assert_eq!(part_two(&input), Some(hash_maps::part_two(&input)));
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use advent_of_code::bitset::BitSet;
+use itertools::Itertools;
+advent_of_code::solution!(2024, 23);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+struct Cpt(char, char);
+
+/// Computers are numbered in order of appearance, and each one has the bitset of its neighbours.
+struct CptNetwork {
+    cpts: Vec<Cpt>,
+    adjacency: Vec<BitSet>,
+}
+
+impl CptNetwork {
+    fn part_one_groups(&self) -> usize {
+        let mut groups = 0;
+        for (c1, neighbours) in self.adjacency.iter().enumerate() {
+            for c2 in neighbours.iter().filter(|&c2| c2 > c1) {
+                let common = neighbours.intersection(&self.adjacency[c2]);
+                groups += common
+                    .iter()
+                    .filter(|&c3| c3 > c2)
+                    .filter(|&c3| [c1, c2, c3].iter().any(|&c| self.cpts[c].0 == 't'))
+                    .count();
+            }
+        }
+        groups
+    }
+
+    /// Vertices in degeneracy order: each one has the fewest neighbours among those left
+    /// when it is picked.
+    fn degeneracy_order(&self) -> Vec<usize> {
+        let mut left = BitSet::full(self.cpts.len());
+        let mut order = Vec::with_capacity(self.cpts.len());
+        while let Some(c) = left
+            .iter()
+            .min_by_key(|&c| self.adjacency[c].intersection_len(&left))
+        {
+            left.remove(c);
+            order.push(c);
+        }
+        order
+    }
+
+    /// Bron–Kerbosch with pivoting: calls `f` on every maximal clique extending `clique` with
+    /// vertices of `candidates`, and not with any vertex of `excluded`.
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: BitSet,
+        mut excluded: BitSet,
+        f: &mut impl FnMut(&[usize]),
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                f(clique);
+            }
+            return;
+        }
+        // Any maximal clique contains the pivot or one of its non-neighbours.
+        let pivot = candidates
+            .iter()
+            .chain(excluded.iter())
+            .max_by_key(|&c| candidates.intersection_len(&self.adjacency[c]))
+            .unwrap();
+        for c in candidates.difference(&self.adjacency[pivot]).iter() {
+            clique.push(c);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(&self.adjacency[c]),
+                excluded.intersection(&self.adjacency[c]),
+                f,
+            );
+            clique.pop();
+            candidates.remove(c);
+            excluded.insert(c);
+        }
+    }
+
+    fn for_each_maximal_clique(&self, mut f: impl FnMut(&[usize])) {
+        let mut later = BitSet::full(self.cpts.len());
+        let mut earlier = BitSet::new(self.cpts.len());
+        for c in self.degeneracy_order() {
+            later.remove(c);
+            self.bron_kerbosch(
+                &mut vec![c],
+                later.intersection(&self.adjacency[c]),
+                earlier.intersection(&self.adjacency[c]),
+                &mut f,
+            );
+            earlier.insert(c);
+        }
+    }
+
+    /// Every maximal clique, each one sorted.
+    fn maximal_cliques(&self) -> Vec<Vec<Cpt>> {
+        let mut cliques = Vec::new();
+        self.for_each_maximal_clique(|clique| {
+            cliques.push(clique.iter().map(|&c| self.cpts[c]).sorted().collect());
+        });
+        cliques
+    }
+
+    fn part_two_biggest_group(&self) -> String {
+        let best_group = self
+            .maximal_cliques()
+            .into_iter()
+            .max_by_key(|clique| clique.len())
+            .unwrap_or_default();
+        best_group
+            .into_iter()
+            .map(|cpt| format!("{}{}", cpt.0, cpt.1))
+            .join(",")
+    }
+}
+
+impl From<&str> for Cpt {
+    fn from(value: &str) -> Self {
+        let val = value.trim();
+        assert!(val.len() == 2);
+        let mut char_iter = val.chars();
+        let c1 = char_iter.next().unwrap();
+        let c2 = char_iter.next().unwrap();
+        Self(c1, c2)
+    }
+}
+
+impl From<&str> for CptNetwork {
+    fn from(value: &str) -> Self {
+        let mut cpts = Vec::new();
+        let mut indices = HashMap::new();
+        let mut index = |cpt: Cpt| {
+            *indices.entry(cpt).or_insert_with(|| {
+                cpts.push(cpt);
+                cpts.len() - 1
+            })
+        };
+        let conns: Vec<(usize, usize)> = value
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.split_once('-').unwrap())
+            .map(|(c1, c2)| (index(Cpt::from(c1)), index(Cpt::from(c2))))
+            .collect();
+
+        let mut adjacency = vec![BitSet::new(cpts.len()); cpts.len()];
+        for (c1, c2) in conns {
+            adjacency[c1].insert(c2);
+            adjacency[c2].insert(c1);
+        }
+        CptNetwork { cpts, adjacency }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(CptNetwork::from(input).part_one_groups())
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    Some(CptNetwork::from(input).part_two_biggest_group())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some("co,de,ka,ta".to_string()));
+    }
+
+    #[test]
+    fn test_maximal_cliques() {
+        let network =
+            CptNetwork::from(advent_of_code::template::read_file("examples", YEAR, DAY).as_str());
+        let cliques = network.maximal_cliques();
+        assert!(cliques.contains(&vec![
+            Cpt('c', 'o'),
+            Cpt('d', 'e'),
+            Cpt('k', 'a'),
+            Cpt('t', 'a')
+        ]));
+        for clique in &cliques {
+            let members: Vec<usize> = clique
+                .iter()
+                .map(|cpt| network.cpts.iter().position(|c| c == cpt).unwrap())
+                .collect();
+            // Every pair is connected...
+            for (&c1, &c2) in members.iter().tuple_combinations() {
+                assert!(network.adjacency[c1].contains(c2));
+            }
+            // ...and no other computer is connected to all of them.
+            let mut common = BitSet::full(network.cpts.len());
+            members
+                .iter()
+                .for_each(|&c| common &= &network.adjacency[c]);
+            assert!(common.is_empty());
+        }
+        assert_eq!(cliques.iter().unique().count(), cliques.len());
+    }
+}
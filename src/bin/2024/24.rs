@@ -0,0 +1,60 @@
+use advent_of_code::{
+    circuit::{number, Circuit},
+    template::artifact,
+};
+
+advent_of_code::solution!(2024, 24);
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let circuit = Circuit::from(input);
+    match circuit.evaluate() {
+        Ok(values) => Some(number(&values, 'z')),
+        Err(err) => {
+            eprintln!("{err}");
+            None
+        }
+    }
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let mut circuit = Circuit::from(input);
+    let swaps = circuit
+        .repair_adder()
+        .inspect_err(|err| eprintln!("{err}"))
+        .ok()?;
+    artifact::record("repaired circuit", circuit.dot());
+
+    let mut wires: Vec<String> = swaps.into_iter().flat_map(|(a, b)| [a, b]).collect();
+    wires.sort();
+    Some(wires.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 1,
+        ));
+        assert_eq!(result, Some(2024));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 2,
+        ));
+        assert_eq!(result.as_deref(), Some("mrq,pvt,qjm,z02"));
+    }
+
+    #[test]
+    fn test_repaired_adder_adds() {
+        let input = advent_of_code::template::read_file_part("examples", YEAR, DAY, 2);
+        let mut circuit = Circuit::from(input.as_str());
+        assert_ne!(part_one(&input), Some(11 + 13));
+        circuit.repair_adder().unwrap();
+        assert_eq!(number(&circuit.evaluate().unwrap(), 'z'), 11 + 13);
+    }
+}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(25);
+advent_of_code::solution!(2024, 25);
 
 const SCHEMA_WIDTH: usize = 5;
 const SCHEMA_HEIGHT: usize = 7;
@@ -141,13 +141,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -0,0 +1,174 @@
+//! Fixed-size set of small integers, stored one bit per value.
+use std::ops::{BitAndAssign, BitOrAssign, SubAssign};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize,
+}
+
+impl BitSet {
+    /// An empty set that can hold `0..capacity`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            words: vec![0; capacity.div_ceil(64)],
+            capacity,
+        }
+    }
+
+    /// The set of every value in `0..capacity`.
+    pub fn full(capacity: usize) -> Self {
+        let mut set = Self::new(capacity);
+        set.words.fill(!0);
+        if !capacity.is_multiple_of(64) {
+            *set.words.last_mut().unwrap() = (1 << (capacity % 64)) - 1;
+        }
+        set
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds `value`, returning whether it was missing.
+    pub fn insert(&mut self, value: usize) -> bool {
+        assert!(value < self.capacity, "{value} out of bitset capacity");
+        let (word, bit) = (value / 64, 1 << (value % 64));
+        let missing = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        missing
+    }
+
+    /// Removes `value`, returning whether it was present.
+    pub fn remove(&mut self, value: usize) -> bool {
+        if value >= self.capacity {
+            return false;
+        }
+        let (word, bit) = (value / 64, 1 << (value % 64));
+        let present = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        present
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        value < self.capacity && self.words[value / 64] & (1 << (value % 64)) != 0
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Values in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set &= other;
+        set
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set |= other;
+        set
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set -= other;
+        set
+    }
+
+    /// Size of the intersection, without building it.
+    pub fn intersection_len(&self, other: &Self) -> usize {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+}
+
+impl BitAndAssign<&BitSet> for BitSet {
+    fn bitand_assign(&mut self, rhs: &BitSet) {
+        assert_eq!(self.capacity, rhs.capacity, "bitsets of different capacity");
+        self.words
+            .iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(a, b)| *a &= b);
+    }
+}
+
+impl BitOrAssign<&BitSet> for BitSet {
+    fn bitor_assign(&mut self, rhs: &BitSet) {
+        assert_eq!(self.capacity, rhs.capacity, "bitsets of different capacity");
+        self.words
+            .iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(a, b)| *a |= b);
+    }
+}
+
+impl SubAssign<&BitSet> for BitSet {
+    fn sub_assign(&mut self, rhs: &BitSet) {
+        assert_eq!(self.capacity, rhs.capacity, "bitsets of different capacity");
+        self.words
+            .iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(a, b)| *a &= !b);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::BitSet;
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = BitSet::new(130);
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(129));
+        assert!(set.contains(129));
+        assert!(!set.contains(200));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(3));
+        assert!(!set.remove(3));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![129]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut a = BitSet::new(100);
+        let mut b = BitSet::new(100);
+        [1, 64, 70, 99].into_iter().for_each(|v| _ = a.insert(v));
+        [64, 99, 5].into_iter().for_each(|v| _ = b.insert(v));
+        assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), vec![64, 99]);
+        assert_eq!(a.intersection_len(&b), 2);
+        assert_eq!(a.union(&b).len(), 5);
+        assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), vec![1, 70]);
+        assert_eq!(BitSet::full(100).len(), 100);
+        assert_eq!(BitSet::full(128).len(), 128);
+    }
+}
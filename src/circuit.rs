@@ -0,0 +1,495 @@
+//! Boolean circuits of two-input gates between named wires.
+//!
+//! A [`Circuit`] is evaluated gate by gate in topological order, so that a loop between wires is
+//! reported instead of never settling. [`Circuit::repair_adder`] checks the gates against the
+//! template of a ripple-carry adder and finds the pairs of gates whose outputs were swapped.
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt::Display,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl From<&str> for Op {
+    fn from(value: &str) -> Self {
+        match value {
+            "AND" => Op::And,
+            "OR" => Op::Or,
+            "XOR" => Op::Xor,
+            _ => panic!("unknown gate '{value}'"),
+        }
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Op::And => "AND",
+            Op::Or => "OR",
+            Op::Xor => "XOR",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Op {
+    pub fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Op::And => a & b,
+            Op::Or => a | b,
+            Op::Xor => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gate {
+    pub op: Op,
+    pub inputs: [String; 2],
+    pub output: String,
+}
+
+impl Gate {
+    fn has_input(&self, wire: &str) -> bool {
+        self.inputs.iter().any(|input| input == wire)
+    }
+
+    /// The input that is not `wire`, if `wire` is one of them.
+    fn other_input(&self, wire: &str) -> Option<&str> {
+        match &self.inputs {
+            [a, b] if a == wire => Some(b),
+            [a, b] if b == wire => Some(a),
+            _ => None,
+        }
+    }
+}
+
+/// Input wires with their value, then gates.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    pub inputs: BTreeMap<String, bool>,
+    pub gates: Vec<Gate>,
+}
+
+impl From<&str> for Circuit {
+    fn from(value: &str) -> Self {
+        let (inputs, gates) = value.split_once("\n\n").unwrap();
+        let inputs = inputs
+            .lines()
+            .map(|l| {
+                let (wire, bit) = l.split_once(": ").unwrap();
+                (wire.to_string(), bit == "1")
+            })
+            .collect();
+        let gates = gates
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| {
+                let (expr, output) = l.split_once(" -> ").unwrap();
+                let [a, op, b] = expr.split(' ').collect::<Vec<_>>()[..] else {
+                    panic!("invalid gate '{l}'");
+                };
+                Gate {
+                    op: Op::from(op),
+                    inputs: [a.to_string(), b.to_string()],
+                    output: output.to_string(),
+                }
+            })
+            .collect();
+        Self { inputs, gates }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// Wires that each depend on the previous one, the first depending on the last.
+    Cycle { wires: Vec<String> },
+    /// A wire read by a gate is neither an input nor the output of a gate.
+    Undriven { wire: String },
+}
+
+impl Error for EvalError {}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::Cycle { wires } => write!(f, "wires loop: {}", wires.join(" -> ")),
+            EvalError::Undriven { wire } => write!(f, "wire {wire} is never set"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdderError {
+    /// The bits of the inputs and outputs do not make an adder.
+    Width { x: usize, y: usize, z: usize },
+    /// No swap of outputs explains the gates computing output `bit`.
+    Unrecognized { bit: usize },
+}
+
+impl Error for AdderError {}
+
+impl Display for AdderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdderError::Width { x, y, z } => {
+                write!(f, "cannot add {x} bits to {y} bits into {z} bits")
+            }
+            AdderError::Unrecognized { bit } => write!(f, "no adder found around bit {bit}"),
+        }
+    }
+}
+
+/// Name of bit `bit` of the number on the wires starting with `prefix`, e.g. `x07`.
+pub fn wire(prefix: char, bit: usize) -> String {
+    format!("{prefix}{bit:02}")
+}
+
+/// The number on the wires starting with `prefix`, wire `{prefix}00` being the lowest bit.
+/// Wires numbered 64 or more do not fit and are left out.
+pub fn number(values: &BTreeMap<String, bool>, prefix: char) -> u64 {
+    values
+        .iter()
+        .filter_map(|(name, &bit)| {
+            let shift = name.strip_prefix(prefix)?.parse::<u32>().ok()?;
+            u64::from(bit).checked_shl(shift)
+        })
+        .fold(0, |n, bit| n | bit)
+}
+
+impl Circuit {
+    /// Gate driving each wire.
+    fn drivers(&self) -> HashMap<&str, usize> {
+        self.gates
+            .iter()
+            .enumerate()
+            .map(|(i, gate)| (gate.output.as_str(), i))
+            .collect()
+    }
+
+    /// Gates, each one after the gates driving its inputs.
+    pub fn topological_order(&self) -> Result<Vec<usize>, EvalError> {
+        let drivers = self.drivers();
+        let mut dependents = vec![Vec::new(); self.gates.len()];
+        let mut pending = vec![0; self.gates.len()];
+        for (i, gate) in self.gates.iter().enumerate() {
+            for input in &gate.inputs {
+                if let Some(&driver) = drivers.get(input.as_str()) {
+                    dependents[driver].push(i);
+                    pending[i] += 1;
+                } else if !self.inputs.contains_key(input) {
+                    return Err(EvalError::Undriven {
+                        wire: input.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut ready: Vec<usize> = (0..self.gates.len()).filter(|&i| pending[i] == 0).collect();
+        let mut order = Vec::with_capacity(self.gates.len());
+        while let Some(i) = ready.pop() {
+            order.push(i);
+            for &j in &dependents[i] {
+                pending[j] -= 1;
+                if pending[j] == 0 {
+                    ready.push(j);
+                }
+            }
+        }
+        if order.len() == self.gates.len() {
+            return Ok(order);
+        }
+
+        // every gate left reads a wire of a gate left: going back from one must loop
+        let mut path = vec![(0..self.gates.len()).find(|&i| pending[i] > 0).unwrap()];
+        loop {
+            let last = *path.last().unwrap();
+            let previous = self.gates[last]
+                .inputs
+                .iter()
+                .filter_map(|input| drivers.get(input.as_str()).copied())
+                .find(|&driver| pending[driver] > 0)
+                .unwrap();
+            if let Some(start) = path.iter().position(|&i| i == previous) {
+                let wires = path[start..]
+                    .iter()
+                    .rev()
+                    .map(|&i| self.gates[i].output.clone())
+                    .collect();
+                return Err(EvalError::Cycle { wires });
+            }
+            path.push(previous);
+        }
+    }
+
+    /// Value of every wire.
+    pub fn evaluate(&self) -> Result<BTreeMap<String, bool>, EvalError> {
+        let mut values = self.inputs.clone();
+        for i in self.topological_order()? {
+            let gate = &self.gates[i];
+            let [a, b] = &gate.inputs;
+            let value = gate.op.apply(values[a], values[b]);
+            values.insert(gate.output.clone(), value);
+        }
+        Ok(values)
+    }
+
+    /// Sets the input wires starting with `prefix` to the bits of `n`.
+    pub fn set_number(&mut self, prefix: char, n: u64) {
+        for (name, bit) in &mut self.inputs {
+            if let Some(Ok(shift)) = name.strip_prefix(prefix).map(str::parse::<u32>) {
+                *bit = n.checked_shr(shift).is_some_and(|n| n & 1 == 1);
+            }
+        }
+    }
+
+    /// Exchanges the wires driven by the gates driving `a` and `b`.
+    pub fn swap_outputs(&mut self, a: &str, b: &str) {
+        for gate in &mut self.gates {
+            if gate.output == a {
+                gate.output = b.to_string();
+            } else if gate.output == b {
+                gate.output = a.to_string();
+            }
+        }
+    }
+
+    fn find(&self, op: Op, a: &str, b: &str) -> Option<&Gate> {
+        self.gates
+            .iter()
+            .find(|gate| gate.op == op && gate.other_input(a) == Some(b))
+    }
+
+    fn find_with_input(&self, op: Op, wire: &str) -> Option<&Gate> {
+        self.gates
+            .iter()
+            .find(|gate| gate.op == op && gate.has_input(wire))
+    }
+
+    fn output(&self, op: Op, a: &str, b: &str, bit: usize) -> Result<String, AdderError> {
+        self.find(op, a, b)
+            .map(|gate| gate.output.clone())
+            .ok_or(AdderError::Unrecognized { bit })
+    }
+
+    /// First swap of outputs needed to match a ripple-carry adder adding the `x` and `y` wires
+    /// into the `z` wires, or `None` if it already matches.
+    ///
+    /// Bit 0 is a half adder. Bit `i` is a full adder, with `s = x ^ y`, `z = s ^ carry`, and the
+    /// carry out `(x & y) | (s & carry)`. The last carry is the highest `z` bit.
+    fn adder_mismatch(&self, bits: usize) -> Result<Option<(String, String)>, AdderError> {
+        let (x, y, z) = (wire('x', 0), wire('y', 0), wire('z', 0));
+        let sum = self.output(Op::Xor, &x, &y, 0)?;
+        if sum != z {
+            return Ok(Some((sum, z)));
+        }
+        let mut carry = self.output(Op::And, &x, &y, 0)?;
+
+        for bit in 1..bits {
+            let (x, y, z) = (wire('x', bit), wire('y', bit), wire('z', bit));
+            let half = self.output(Op::Xor, &x, &y, bit)?;
+            let both = self.output(Op::And, &x, &y, bit)?;
+
+            // either `half` or `carry` is not what the sum gate reads
+            let Some(sum) = self.find(Op::Xor, &half, &carry) else {
+                if let Some(other) = self
+                    .find_with_input(Op::Xor, &carry)
+                    .and_then(|gate| gate.other_input(&carry))
+                {
+                    return Ok(Some((half, other.to_string())));
+                }
+                if let Some(other) = self
+                    .find_with_input(Op::Xor, &half)
+                    .and_then(|gate| gate.other_input(&half))
+                {
+                    return Ok(Some((carry, other.to_string())));
+                }
+                return Err(AdderError::Unrecognized { bit });
+            };
+            if sum.output != z {
+                return Ok(Some((sum.output.clone(), z)));
+            }
+
+            let carried = self.output(Op::And, &half, &carry, bit)?;
+            let Some(carry_out) = self.find(Op::Or, &both, &carried) else {
+                if let Some(other) = self
+                    .find_with_input(Op::Or, &both)
+                    .and_then(|gate| gate.other_input(&both))
+                {
+                    return Ok(Some((carried, other.to_string())));
+                }
+                if let Some(other) = self
+                    .find_with_input(Op::Or, &carried)
+                    .and_then(|gate| gate.other_input(&carried))
+                {
+                    return Ok(Some((both, other.to_string())));
+                }
+                return Err(AdderError::Unrecognized { bit });
+            };
+            carry = carry_out.output.clone();
+        }
+
+        let z = wire('z', bits);
+        Ok((carry != z).then_some((carry, z)))
+    }
+
+    /// Pairs of wires whose gates must swap outputs for the circuit to add the `x` and `y` wires
+    /// into the `z` wires, found bit by bit from the lowest. The swaps are applied.
+    pub fn repair_adder(&mut self) -> Result<Vec<(String, String)>, AdderError> {
+        let count = |prefix: char| {
+            let inputs = self.inputs.keys().filter(|w| w.starts_with(prefix)).count();
+            let outputs = self
+                .gates
+                .iter()
+                .filter(|g| g.output.starts_with(prefix))
+                .count();
+            inputs + outputs
+        };
+        let (x, y, z) = (count('x'), count('y'), count('z'));
+        if x == 0 || x != y || z != x + 1 {
+            return Err(AdderError::Width { x, y, z });
+        }
+
+        let mut swaps = Vec::new();
+        while let Some((a, b)) = self.adder_mismatch(x)? {
+            // each swap fixes a gate for good: more swaps than gates means going round in circles
+            if swaps.len() >= self.gates.len() || swaps.contains(&(a.clone(), b.clone())) {
+                return Err(AdderError::Unrecognized { bit: x });
+            }
+            self.swap_outputs(&a, &b);
+            swaps.push((a, b));
+        }
+        Ok(swaps)
+    }
+
+    /// The circuit in Graphviz DOT, rendered when displayed: input wires as boxes, gates as
+    /// circles, and other wires as plain labels.
+    pub fn dot(&self) -> Dot<'_> {
+        Dot(self)
+    }
+}
+
+pub struct Dot<'a>(&'a Circuit);
+
+impl Display for Dot<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let circuit = self.0;
+        writeln!(f, "digraph circuit {{")?;
+        writeln!(f, "  rankdir=LR;")?;
+        for (wire, bit) in &circuit.inputs {
+            writeln!(
+                f,
+                "  {wire} [shape=box, label=\"{wire}={}\"];",
+                u8::from(*bit)
+            )?;
+        }
+        for (i, gate) in circuit.gates.iter().enumerate() {
+            writeln!(f, "  g{i} [shape=circle, label=\"{}\"];", gate.op)?;
+            writeln!(f, "  {} [shape=plaintext];", gate.output)?;
+            for input in &gate.inputs {
+                writeln!(f, "  {input} -> g{i};")?;
+            }
+            writeln!(f, "  g{i} -> {};", gate.output)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    /// A 3 bits adder, its gates in no particular order.
+    const ADDER: &str = "x00: 0\nx01: 0\nx02: 0\ny00: 0\ny01: 0\ny02: 0
+
+x00 XOR y00 -> z00
+x00 AND y00 -> c00
+x01 XOR y01 -> h01
+x01 AND y01 -> b01
+h01 XOR c00 -> z01
+h01 AND c00 -> k01
+b01 OR k01 -> c01
+y02 XOR x02 -> h02
+c01 AND h02 -> k02
+x02 AND y02 -> b02
+c01 XOR h02 -> z02
+k02 OR b02 -> z03";
+
+    fn sum(circuit: &Circuit, a: u64, b: u64) -> Result<u64, EvalError> {
+        let mut circuit = circuit.clone();
+        circuit.set_number('x', a);
+        circuit.set_number('y', b);
+        Ok(number(&circuit.evaluate()?, 'z'))
+    }
+
+    #[test]
+    fn evaluates_adder() {
+        let circuit = Circuit::from(ADDER);
+        for (a, b) in (0..8).flat_map(|a| (0..8).map(move |b| (a, b))) {
+            assert_eq!(sum(&circuit, a, b), Ok(a + b));
+        }
+        assert!(circuit
+            .dot()
+            .to_string()
+            .contains("  g4 [shape=circle, label=\"XOR\"];\n  z01 [shape=plaintext];"));
+    }
+
+    #[test]
+    fn numbers() {
+        let values: BTreeMap<String, bool> = [("z00", true), ("z02", true), ("z64", true)]
+            .into_iter()
+            .chain([("é01", true), ("x01", true)])
+            .map(|(wire, bit)| (wire.to_string(), bit))
+            .collect();
+        assert_eq!(number(&values, 'z'), 0b101);
+        assert_eq!(number(&values, 'é'), 0b10);
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let mut circuit = Circuit::from(ADDER);
+        // the sum gate of bit 1 now drives the wire it reads
+        circuit.swap_outputs("h01", "z01");
+        let Err(EvalError::Cycle { wires }) = circuit.evaluate() else {
+            panic!("h01 depends on itself");
+        };
+        assert_eq!(wires, vec!["h01"]);
+
+        circuit.gates.pop();
+        circuit.gates[0].inputs[0] = "x09".to_string();
+        assert_eq!(
+            circuit.topological_order(),
+            Err(EvalError::Undriven {
+                wire: "x09".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn repairs_swapped_outputs() {
+        let mut circuit = Circuit::from(ADDER);
+        assert_eq!(circuit.clone().repair_adder(), Ok(vec![]));
+
+        circuit.swap_outputs("h01", "b01");
+        circuit.swap_outputs("z02", "k02");
+        circuit.swap_outputs("c01", "z03");
+        assert_ne!(sum(&circuit, 3, 5), Ok(8));
+        let mut swaps: Vec<String> = circuit
+            .repair_adder()
+            .unwrap()
+            .into_iter()
+            .flat_map(|(a, b)| [a, b])
+            .collect();
+        swaps.sort();
+        assert_eq!(swaps, ["b01", "c01", "h01", "k02", "z02", "z03"]);
+        for (a, b) in (0..8).flat_map(|a| (0..8).map(move |b| (a, b))) {
+            assert_eq!(sum(&circuit, a, b), Ok(a + b));
+        }
+    }
+}
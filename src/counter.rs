@@ -0,0 +1,221 @@
+//! Count map (multiset) collection.
+//!
+//! [`Counter`] counts occurrences of keys, backed by a [`HashMap`] ([`HashCounter`]) or by a
+//! [`BTreeMap`] ([`BTreeCounter`]) when keys are only `Ord` or should iterate in order.
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+/// A map from keys to their count, that a [`Counter`] can be backed by.
+pub trait CountMap<K>: Default + IntoIterator<Item = (K, usize)> {
+    /// Count of `key`, inserting a zero count if it is missing.
+    fn count_mut(&mut self, key: K) -> &mut usize;
+    fn count(&self, key: &K) -> usize;
+    fn remove(&mut self, key: &K) -> Option<usize>;
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a usize)>
+    where
+        K: 'a;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Hash + Eq> CountMap<K> for HashMap<K, usize> {
+    fn count_mut(&mut self, key: K) -> &mut usize {
+        self.entry(key).or_insert(0)
+    }
+    fn count(&self, key: &K) -> usize {
+        self.get(key).copied().unwrap_or(0)
+    }
+    fn remove(&mut self, key: &K) -> Option<usize> {
+        HashMap::remove(self, key)
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a usize)>
+    where
+        K: 'a,
+    {
+        HashMap::iter(self)
+    }
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+impl<K: Ord> CountMap<K> for BTreeMap<K, usize> {
+    fn count_mut(&mut self, key: K) -> &mut usize {
+        self.entry(key).or_insert(0)
+    }
+    fn count(&self, key: &K) -> usize {
+        self.get(key).copied().unwrap_or(0)
+    }
+    fn remove(&mut self, key: &K) -> Option<usize> {
+        BTreeMap::remove(self, key)
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a usize)>
+    where
+        K: 'a,
+    {
+        BTreeMap::iter(self)
+    }
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+}
+
+/// A multiset: counts how many times each key was added.
+///
+/// ```
+/// # use advent_of_code::counter::HashCounter;
+/// let counter: HashCounter<char> = "hello".chars().collect();
+/// assert_eq!(counter.get(&'l'), 2);
+/// assert_eq!(counter.most_common(1), vec![(&'l', 2)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<K, M = HashMap<K, usize>> {
+    counts: M,
+    _key: PhantomData<K>,
+}
+
+/// A [`Counter`] backed by a [`HashMap`].
+pub type HashCounter<K> = Counter<K, HashMap<K, usize>>;
+/// A [`Counter`] backed by a [`BTreeMap`], iterating keys in ascending order.
+pub type BTreeCounter<K> = Counter<K, BTreeMap<K, usize>>;
+
+impl<K, M: CountMap<K>> Default for Counter<K, M> {
+    fn default() -> Self {
+        Self {
+            counts: M::default(),
+            _key: PhantomData,
+        }
+    }
+}
+
+impl<K, M: CountMap<K>> Counter<K, M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `n` occurrences of `key`.
+    pub fn add(&mut self, key: K, n: usize) {
+        *self.counts.count_mut(key) += n;
+    }
+
+    pub fn increment(&mut self, key: K) {
+        self.add(key, 1);
+    }
+
+    /// Count of `key`, zero if it was never added.
+    pub fn get(&self, key: &K) -> usize {
+        self.counts.count(key)
+    }
+
+    /// Removes `key`, returning its count.
+    pub fn remove(&mut self, key: &K) -> Option<usize> {
+        self.counts.remove(key)
+    }
+
+    /// Adds all the counts of `other`.
+    pub fn merge(&mut self, other: Self) {
+        for (key, n) in other {
+            self.add(key, n);
+        }
+    }
+
+    /// Number of distinct keys.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Sum of all counts.
+    pub fn total(&self) -> usize {
+        self.counts.iter().map(|(_, &n)| n).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, usize)> {
+        self.counts.iter().map(|(k, &n)| (k, n))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.counts.iter().map(|(k, _)| k)
+    }
+
+    /// The `n` keys with the highest counts, highest first.
+    /// Ties keep the iteration order of the backing map.
+    pub fn most_common(&self, n: usize) -> Vec<(&K, usize)> {
+        let mut counts: Vec<(&K, usize)> = self.iter().collect();
+        counts.sort_by_key(|&(_, n)| Reverse(n));
+        counts.truncate(n);
+        counts
+    }
+}
+
+impl<K, M: CountMap<K>> IntoIterator for Counter<K, M> {
+    type Item = (K, usize);
+    type IntoIter = M::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+impl<K, M: CountMap<K>> FromIterator<K> for Counter<K, M> {
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K, M: CountMap<K>> Extend<K> for Counter<K, M> {
+    fn extend<T: IntoIterator<Item = K>>(&mut self, iter: T) {
+        for key in iter {
+            self.increment(key);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BTreeCounter, HashCounter};
+
+    #[test]
+    fn counts_and_merges() {
+        let mut counter: HashCounter<u32> = [3, 4, 2, 1, 3, 3].into_iter().collect();
+        assert_eq!(counter.get(&3), 3);
+        assert_eq!(counter.get(&5), 0);
+        assert_eq!(counter.len(), 4);
+        assert_eq!(counter.total(), 6);
+
+        let other: HashCounter<u32> = [4, 5].into_iter().collect();
+        counter.merge(other);
+        assert_eq!(counter.get(&4), 2);
+        assert_eq!(counter.get(&5), 1);
+        assert_eq!(counter.total(), 8);
+    }
+
+    #[test]
+    fn most_common_first() {
+        let mut counter: BTreeCounter<&str> = BTreeCounter::new();
+        counter.add("a", 2);
+        counter.add("b", 5);
+        counter.add("c", 2);
+        counter.increment("a");
+        assert_eq!(counter.most_common(2), vec![(&"b", 5), (&"a", 3)]);
+        assert_eq!(counter.most_common(10).len(), 3);
+    }
+
+    #[test]
+    fn ordered_iteration() {
+        let counter: BTreeCounter<char> = "counter".chars().collect();
+        let keys: String = counter.keys().collect();
+        assert_eq!(keys, "cenortu");
+    }
+}
@@ -0,0 +1,119 @@
+//! Paths on grids where every move climbs, which makes the moves a DAG.
+//!
+//! [`PathCounts`] goes once over the cells from the highest rank down and gives, for every cell,
+//! the number of paths to a target and the set of targets it reaches.
+use crate::{bitset::BitSet, Bound, Dir, Pos};
+
+#[derive(Debug)]
+pub struct PathCounts {
+    paths: Vec<Vec<u64>>,
+    reachable: Vec<Vec<BitSet>>,
+    targets: Vec<Pos>,
+}
+
+impl PathCounts {
+    /// Moves go between neighbouring cells (not diagonally) `from` and `to` for which
+    /// `is_edge(from, to)` holds, and must climb: `rank(from) < rank(to)`.
+    ///
+    /// # Panics
+    ///
+    /// If a move does not climb, or a number of paths overflows a `u64`.
+    pub fn new<T>(
+        grid: &[Vec<T>],
+        rank: impl Fn(&T) -> usize,
+        is_edge: impl Fn(&T, &T) -> bool,
+        is_target: impl Fn(&T) -> bool,
+    ) -> Self {
+        let bound = Bound {
+            x_bound: grid.first().map_or(0, Vec::len),
+            y_bound: grid.len(),
+        };
+        let cells =
+            || (0..bound.y_bound).flat_map(move |y| (0..bound.x_bound).map(move |x| Pos { x, y }));
+        let at = |pos: Pos| &grid[pos.y][pos.x];
+
+        // counting sort by rank, for a topological order
+        let max_rank = cells().map(|pos| rank(at(pos))).max().unwrap_or(0);
+        let mut by_rank: Vec<Vec<Pos>> = vec![Vec::new(); max_rank + 1];
+        cells().for_each(|pos| by_rank[rank(at(pos))].push(pos));
+
+        let targets: Vec<Pos> = cells().filter(|&pos| is_target(at(pos))).collect();
+        let mut target_ids = vec![vec![None; bound.x_bound]; bound.y_bound];
+        for (id, pos) in targets.iter().enumerate() {
+            target_ids[pos.y][pos.x] = Some(id);
+        }
+
+        let mut paths = vec![vec![0u64; bound.x_bound]; bound.y_bound];
+        let mut reachable = vec![vec![BitSet::new(targets.len()); bound.x_bound]; bound.y_bound];
+        for pos in by_rank.iter().rev().flatten().copied() {
+            let mut count = 0u64;
+            let mut reached = BitSet::new(targets.len());
+            if let Some(id) = target_ids[pos.y][pos.x] {
+                count = 1;
+                reached.insert(id);
+            }
+            for dir in Dir::all() {
+                let Some(next) = dir.apply_bounded(&pos, &bound) else {
+                    continue;
+                };
+                if !is_edge(at(pos), at(next)) {
+                    continue;
+                }
+                assert!(
+                    rank(at(pos)) < rank(at(next)),
+                    "move from {pos:?} to {next:?} does not climb"
+                );
+                count = count
+                    .checked_add(paths[next.y][next.x])
+                    .expect("too many paths to count");
+                reached |= &reachable[next.y][next.x];
+            }
+            paths[pos.y][pos.x] = count;
+            reachable[pos.y][pos.x] = reached;
+        }
+        Self {
+            paths,
+            reachable,
+            targets,
+        }
+    }
+
+    /// Number of paths from `pos` to any target.
+    pub fn paths(&self, pos: Pos) -> u64 {
+        self.paths[pos.y][pos.x]
+    }
+
+    /// Targets reachable from `pos`, by index into [`PathCounts::targets`].
+    pub fn reachable(&self, pos: Pos) -> &BitSet {
+        &self.reachable[pos.y][pos.x]
+    }
+
+    /// Target cells, in reading order.
+    pub fn targets(&self) -> &[Pos] {
+        &self.targets
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_paths_and_targets() {
+        // four ways from the 0 to the 3s on the top two rows: three through (1, 0), one through
+        // (0, 1); the 3 below is out of reach
+        let grid: Vec<Vec<u8>> = vec![vec![0, 1, 2, 3], vec![1, 2, 3, 0], vec![3, 9, 9, 9]];
+        let counts = PathCounts::new(&grid, |&h| h as usize, |&a, &b| b == a + 1, |&h| h == 3);
+        let start = Pos { x: 0, y: 0 };
+        assert_eq!(counts.paths(start), 4);
+        assert_eq!(counts.targets().len(), 3);
+        let reached: Vec<Pos> = counts
+            .reachable(start)
+            .iter()
+            .map(|id| counts.targets()[id])
+            .collect();
+        assert_eq!(reached, vec![Pos { x: 3, y: 0 }, Pos { x: 2, y: 1 }]);
+        assert_eq!(counts.paths(Pos { x: 0, y: 2 }), 1);
+        assert_eq!(counts.paths(Pos { x: 1, y: 2 }), 0);
+    }
+}
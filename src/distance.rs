@@ -0,0 +1,176 @@
+//! Shortest distances on a grid, and shortcuts through its walls.
+//!
+//! A [`DistanceField`] holds the number of steps from a source to every reachable cell. With one
+//! field from the start and one from the end, [`cheats`] finds every way to save time by going
+//! through walls for a few steps, on any maze (branches and dead ends included).
+use std::collections::VecDeque;
+
+use crate::{Bound, Dir, Pos};
+
+#[derive(Debug)]
+pub struct DistanceField {
+    source: Pos,
+    dist: Vec<Vec<Option<usize>>>,
+    bound: Bound,
+}
+
+impl DistanceField {
+    /// Breadth-first search from `source` through the cells for which `passable` holds.
+    pub fn bfs<T>(grid: &[Vec<T>], source: Pos, passable: impl Fn(&T) -> bool) -> Self {
+        let bound = Bound {
+            x_bound: grid.first().map_or(0, Vec::len),
+            y_bound: grid.len(),
+        };
+        let mut dist = vec![vec![None; bound.x_bound]; bound.y_bound];
+        dist[source.y][source.x] = Some(0);
+        let mut queue = VecDeque::from([(source, 0)]);
+        while let Some((pos, d)) = queue.pop_front() {
+            for dir in Dir::all() {
+                let Some(next) = dir.apply_bounded(&pos, &bound) else {
+                    continue;
+                };
+                if dist[next.y][next.x].is_none() && passable(&grid[next.y][next.x]) {
+                    dist[next.y][next.x] = Some(d + 1);
+                    queue.push_back((next, d + 1));
+                }
+            }
+        }
+        Self {
+            source,
+            dist,
+            bound,
+        }
+    }
+
+    pub fn source(&self) -> Pos {
+        self.source
+    }
+
+    /// Steps from the source to `pos`, if it can be reached.
+    pub fn get(&self, pos: Pos) -> Option<usize> {
+        self.bound
+            .check(pos)
+            .and_then(|pos| self.dist[pos.y][pos.x])
+    }
+
+    /// Reachable cells with their distance, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, usize)> + '_ {
+        self.dist.iter().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter_map(move |(x, d)| d.map(|d| (Pos { x, y }, d)))
+        })
+    }
+}
+
+/// Cells at a Manhattan distance between 1 and `radius` of `center`, within `bound`, with their
+/// distance.
+pub fn diamond(center: Pos, radius: usize, bound: &Bound) -> impl Iterator<Item = (Pos, usize)> {
+    let (cx, cy, r) = (center.x as isize, center.y as isize, radius as isize);
+    let (x_bound, y_bound) = (bound.x_bound as isize, bound.y_bound as isize);
+    (-r..=r).flat_map(move |dy| {
+        let span = r - dy.abs();
+        (-span..=span).filter_map(move |dx| {
+            let (x, y) = (cx + dx, cy + dy);
+            let in_bound = (0..x_bound).contains(&x) && (0..y_bound).contains(&y);
+            (in_bound && (dx, dy) != (0, 0)).then(|| {
+                let pos = Pos {
+                    x: x as usize,
+                    y: y as usize,
+                };
+                (pos, dx.unsigned_abs() + dy.unsigned_abs())
+            })
+        })
+    })
+}
+
+/// Going straight from `from` to `to`, walls or not, in `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cheat {
+    pub from: Pos,
+    pub to: Pos,
+    pub length: usize,
+    /// Steps saved on the shortest path from start to end.
+    pub saving: usize,
+}
+
+/// Every cheat of at most `max_length` steps that shortens the race, given the distances from
+/// the start and to the end.
+///
+/// Each reachable cell only looks at the cells within `max_length` of it, instead of every
+/// other cell.
+pub fn cheats<'a>(
+    from_start: &'a DistanceField,
+    to_end: &'a DistanceField,
+    max_length: usize,
+) -> impl Iterator<Item = Cheat> + 'a {
+    let best = to_end.get(from_start.source());
+    best.into_iter().flat_map(move |best| {
+        from_start.iter().flat_map(move |(from, before)| {
+            diamond(from, max_length, &from_start.bound).filter_map(move |(to, length)| {
+                let after = to_end.get(to)?;
+                let saving = best.checked_sub(before + length + after)?;
+                (saving > 0).then_some(Cheat {
+                    from,
+                    to,
+                    length,
+                    saving,
+                })
+            })
+        })
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Vec<Vec<char>> {
+        input.lines().map(|l| l.chars().collect()).collect()
+    }
+
+    #[test]
+    fn distances() {
+        let maze = grid("#####\n#..##\n#.#.#\n#...#\n#####");
+        let field = DistanceField::bfs(&maze, Pos { x: 1, y: 1 }, |&c| c != '#');
+        assert_eq!(field.get(Pos { x: 3, y: 3 }), Some(4));
+        assert_eq!(field.get(Pos { x: 2, y: 1 }), Some(1));
+        assert_eq!(field.get(Pos { x: 3, y: 2 }), Some(5));
+        assert_eq!(field.get(Pos { x: 0, y: 0 }), None);
+        assert_eq!(field.get(Pos { x: 9, y: 9 }), None);
+        assert_eq!(field.iter().count(), 7);
+    }
+
+    #[test]
+    fn diamond_cells() {
+        let bound = Bound {
+            x_bound: 10,
+            y_bound: 10,
+        };
+        assert_eq!(diamond(Pos { x: 5, y: 5 }, 2, &bound).count(), 12);
+        assert_eq!(diamond(Pos { x: 0, y: 0 }, 2, &bound).count(), 5);
+    }
+
+    #[test]
+    fn cheats_with_branches() {
+        // a dead end on the right, and a long way around the wall above the end
+        let maze = grid("#######\n#S....#\n#.###.#\n#.#E#.#\n#.#.#.#\n#...#.#\n#######");
+        let passable = |&c: &char| c != '#';
+        let start = DistanceField::bfs(&maze, Pos { x: 1, y: 1 }, passable);
+        let end = DistanceField::bfs(&maze, Pos { x: 3, y: 3 }, passable);
+        assert_eq!(start.get(end.source()), Some(8));
+
+        let found: Vec<Cheat> = cheats(&start, &end, 2).collect();
+        assert!(found.contains(&Cheat {
+            from: Pos { x: 3, y: 1 },
+            to: Pos { x: 3, y: 3 },
+            length: 2,
+            saving: 4
+        }));
+        assert!(found
+            .iter()
+            .all(|c| c.length <= 2 && start.get(c.from).is_some()));
+        assert_eq!(found.iter().filter(|c| c.saving == 4).count(), 2);
+        assert_eq!(found.iter().map(|c| c.saving).max(), Some(4));
+    }
+}
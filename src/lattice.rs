@@ -0,0 +1,136 @@
+//! Grid points on lines, rays and segments.
+//!
+//! [`ray`] and [`line`] give the points a line goes exactly through, stepping by the
+//! [minimized](DirVec::minimize) direction so that none is skipped. [`segment`] draws the cells
+//! closest to a segment, as Bresenham's algorithm does.
+use crate::{Bound, DirVec, Pos};
+
+/// Points `from`, `from + step`, `from + 2 * step`... as long as they are within `bound`.
+/// Empty if `from` is not; only `from` if `step` is zero.
+pub fn ray(from: Pos, step: DirVec, bound: &Bound) -> impl Iterator<Item = Pos> + '_ {
+    let first = bound.check(from);
+    std::iter::successors(first, move |&pos| {
+        if step.dx == 0 && step.dy == 0 {
+            return None;
+        }
+        step.apply(pos).and_then(|pos| bound.check(pos))
+    })
+}
+
+/// Every grid point within `bound` on the line through `a` and `b`, in order along the line
+/// from `a` to `b`. Empty if `a` is not within `bound`.
+///
+/// ```
+/// # use advent_of_code::{lattice::line, Bound, Pos};
+/// let bound = Bound { x_bound: 5, y_bound: 5 };
+/// let points: Vec<Pos> = line(Pos { x: 2, y: 2 }, Pos { x: 4, y: 4 }, &bound).collect();
+/// assert_eq!(points.len(), 5);
+/// assert_eq!(points[0], Pos { x: 0, y: 0 });
+/// ```
+pub fn line(a: Pos, b: Pos, bound: &Bound) -> impl Iterator<Item = Pos> + '_ {
+    let step = DirVec::new(a, b).minimize();
+    let before: Vec<Pos> = ray(a, step.opposite(), bound).skip(1).collect();
+    before.into_iter().rev().chain(ray(a, step, bound))
+}
+
+/// Cells from `a` to `b` included, each one next to the previous one (diagonals included) and
+/// as close as possible to the straight segment.
+pub fn segment(a: Pos, b: Pos) -> impl Iterator<Item = Pos> {
+    let (x1, y1) = (b.x as isize, b.y as isize);
+    let (dx, dy) = ((x1 - a.x as isize).abs(), -(y1 - a.y as isize).abs());
+    let (sx, sy) = (
+        if a.x < b.x { 1 } else { -1 },
+        if a.y < b.y { 1 } else { -1 },
+    );
+    let mut err = dx + dy;
+    let mut next = Some((a.x as isize, a.y as isize));
+    std::iter::from_fn(move || {
+        let (x, y) = next?;
+        next = if (x, y) == (x1, y1) {
+            None
+        } else {
+            let (mut nx, mut ny) = (x, y);
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                nx += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                ny += sy;
+            }
+            Some((nx, ny))
+        };
+        Some(Pos {
+            x: x as usize,
+            y: y as usize,
+        })
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    const BOUND: Bound = Bound {
+        x_bound: 10,
+        y_bound: 10,
+    };
+
+    fn pos(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+
+    #[test]
+    fn minimize() {
+        assert_eq!(
+            DirVec { dx: 4, dy: -6 }.minimize(),
+            DirVec { dx: 2, dy: -3 }
+        );
+        assert_eq!(
+            DirVec { dx: 0, dy: -6 }.minimize(),
+            DirVec { dx: 0, dy: -1 }
+        );
+        assert_eq!(DirVec { dx: 0, dy: 0 }.minimize(), DirVec { dx: 0, dy: 0 });
+    }
+
+    #[test]
+    fn rays() {
+        let step = DirVec { dx: 3, dy: 1 };
+        let points: Vec<Pos> = ray(pos(1, 1), step, &BOUND).collect();
+        assert_eq!(points, vec![pos(1, 1), pos(4, 2), pos(7, 3)]);
+        assert_eq!(ray(pos(10, 1), step, &BOUND).count(), 0);
+        assert_eq!(ray(pos(1, 1), DirVec { dx: 0, dy: 0 }, &BOUND).count(), 1);
+    }
+
+    #[test]
+    fn lines_with_non_primitive_offsets() {
+        // (2, 2) apart: the points in between are on the line too
+        let points: Vec<Pos> = line(pos(3, 3), pos(5, 5), &BOUND).collect();
+        assert_eq!(points, (0..10).map(|i| pos(i, i)).collect::<Vec<_>>());
+
+        // (4, 6) apart, steps of (2, 3)
+        let points: Vec<Pos> = line(pos(7, 1), pos(3, 7), &BOUND).collect();
+        assert_eq!(points, vec![pos(7, 1), pos(5, 4), pos(3, 7)]);
+    }
+
+    #[test]
+    fn segments() {
+        let cells: Vec<Pos> = segment(pos(0, 0), pos(4, 2)).collect();
+        assert_eq!(cells.len(), 5);
+        assert_eq!(cells.first(), Some(&pos(0, 0)));
+        assert_eq!(cells.last(), Some(&pos(4, 2)));
+        assert!(cells.contains(&pos(2, 1)));
+        assert!(cells
+            .windows(2)
+            .all(|w| w[0].x.abs_diff(w[1].x) <= 1 && w[0].y.abs_diff(w[1].y) <= 1));
+
+        // the lattice points of a segment are all drawn
+        let cells: Vec<Pos> = segment(pos(7, 8), pos(1, 2)).collect();
+        assert_eq!(cells.len(), 7);
+        assert!(line(pos(7, 8), pos(1, 2), &BOUND)
+            .filter(|p| (1..=7).contains(&p.x))
+            .all(|p| cells.contains(&p)));
+        assert_eq!(segment(pos(3, 3), pos(3, 3)).count(), 1);
+    }
+}
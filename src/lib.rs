@@ -1,6 +1,19 @@
 use std::cmp::Ordering;
 
+pub mod bitset;
+pub mod circuit;
+pub mod counter;
+pub mod dag;
+pub mod distance;
+pub mod lattice;
+pub mod math;
+pub mod memo;
+pub mod pattern;
+pub mod region;
+pub mod simulation;
 pub mod template;
+pub mod trie;
+pub mod vm;
 
 // Use this file to add helper functions and additional modules.
 
@@ -133,27 +146,17 @@ impl DirVec {
         }
     }
 
-    /* wasn't needed */
-    // fn minimize(self) -> Self {
-    //     // ugly get divisors of dx and dy, then search commons:
-    //     let dx_divisors: Vec<isize> = (0..self.dx)
-    //         .filter(|&n| self.dx.rem_euclid(n) == 0)
-    //         .collect();
-
-    //     let dy_divisors = (0..self.dy).filter(|&n| self.dy.rem_euclid(n) == 0);
-
-    //     // find common divisors
-    //     let mut common_divisors: Vec<isize> =
-    //         dy_divisors.filter(|n| dx_divisors.contains(n)).collect();
-    //     common_divisors.sort();
-    //     match common_divisors.last() {
-    //         Some(&div) => Self {
-    //             dx: self.dx.div_euclid(div),
-    //             dy: self.dy.div_euclid(div),
-    //         },
-    //         None => self,
-    //     }
-    // }
+    /// Shortest vector with the same direction and integer coordinates: divides both
+    /// coordinates by their gcd. The zero vector stays zero.
+    pub fn minimize(self) -> Self {
+        match math::gcd(self.dx as i64, self.dy as i64) {
+            0 => self,
+            div => Self {
+                dx: self.dx / div as isize,
+                dy: self.dy / div as isize,
+            },
+        }
+    }
 
     pub fn apply(&self, pos: Pos) -> Option<Pos> {
         Some(Pos {
@@ -7,44 +7,65 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{Day, Year};
     use std::process;
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
+            dry_run: bool,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
+            verbose: bool,
             submit: Option<u8>,
         },
         All {
+            year: Option<Year>,
             release: bool,
         },
         Time {
+            year: Option<Year>,
             all: bool,
             day: Option<Day>,
             store: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: Year,
+        },
+    }
+
+    /// Falls back to the `AOC_YEAR` configured in `.cargo/config.toml` if `--year` was not passed.
+    fn year_or_default(year: Option<Year>) -> Result<Year, &'static str> {
+        year.or_else(Year::from_env)
+            .ok_or("no year specified, pass `--year` or set `AOC_YEAR`.")
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        // NOTE: options have to be parsed before free arguments.
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
             },
             Some("time") => {
@@ -52,30 +73,39 @@ mod args {
                 let store = args.contains("--store");
 
                 AppArguments::Time {
+                    year,
                     all,
                     day: args.opt_free_from_str()?,
                     store,
                 }
             }
             Some("download") => AppArguments::Download {
+                year: year_or_default(year)?,
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
+                year: year_or_default(year)?,
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
+                year: year_or_default(year)?,
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                dry_run: args.contains("--dry-run"),
             },
             Some("solve") => AppArguments::Solve {
+                year: year_or_default(year)?,
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                verbose: args.contains("--verbose"),
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                year: year_or_default(year)?,
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,33 +132,42 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All { year, release } => all::handle(year, release),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+            } => time::handle(year, day, all, store),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
+                dry_run,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+                scaffold::handle(year, day, overwrite, dry_run);
+                if download && !dry_run {
+                    download::handle(year, day);
                 }
             }
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 dhat,
+                verbose,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+            } => solve::handle(year, day, release, dhat, verbose, submit),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
+            AppArguments::Today { year } => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        scaffold::handle(year, day, false, false);
+                        download::handle(year, day);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,383 @@
+//! Number theory and exact linear algebra.
+//!
+//! Linear systems are solved over [`Rational`]s, so there is no rounding to worry about, and
+//! degenerate systems are reported as [`LinearSolution::None`] or [`LinearSolution::Infinite`]
+//! instead of dividing by zero. The integer helpers work on `i64` and widen to `i128` where
+//! products could overflow.
+use std::{error::Error, fmt::Display};
+
+use fraction::GenericFraction;
+
+/// Exact rational number.
+pub type Rational = GenericFraction<u128>;
+
+pub fn rational(n: i64) -> Rational {
+    if n < 0 {
+        Rational::new_neg(n.unsigned_abs(), 1u64)
+    } else {
+        Rational::new(n as u64, 1u64)
+    }
+}
+
+/// The value of `r` if it is an integer that fits an `i64`.
+pub fn to_integer(r: &Rational) -> Option<i64> {
+    if r.denom()? != &1 {
+        return None;
+    }
+    let numer = i64::try_from(*r.numer()?).ok()?;
+    Some(if r.is_sign_negative() { -numer } else { numer })
+}
+
+fn is_zero(r: &Rational) -> bool {
+    r.numer() == Some(&0)
+}
+
+/// Kind of solution set of a linear system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearSolution {
+    Unique(Vec<Rational>),
+    /// The system is inconsistent.
+    None,
+    /// The system is underdetermined: `particular` is the solution with every `free` variable
+    /// set to zero.
+    Infinite {
+        particular: Vec<Rational>,
+        free: Vec<usize>,
+    },
+}
+
+/// Solves `a * x = b` by Gauss-Jordan elimination, for any number of equations (rows of `a`)
+/// and unknowns (columns of `a`).
+///
+/// ```
+/// # use advent_of_code::math::{rational, solve_linear, LinearSolution};
+/// let a = vec![vec![rational(2), rational(1)], vec![rational(1), rational(-1)]];
+/// let b = vec![rational(5), rational(1)];
+/// assert_eq!(solve_linear(a, b), LinearSolution::Unique(vec![rational(2), rational(1)]));
+/// ```
+pub fn solve_linear(mut a: Vec<Vec<Rational>>, mut b: Vec<Rational>) -> LinearSolution {
+    assert_eq!(a.len(), b.len(), "one right-hand side per equation");
+    let n_vars = a.first().map_or(0, |row| row.len());
+    assert!(
+        a.iter().all(|row| row.len() == n_vars),
+        "every equation needs the same number of unknowns"
+    );
+
+    let mut pivots = Vec::new();
+    let mut row = 0;
+    for col in 0..n_vars {
+        let Some(pivot) = (row..a.len()).find(|&r| !is_zero(&a[r][col])) else {
+            continue;
+        };
+        a.swap(row, pivot);
+        b.swap(row, pivot);
+
+        let inv = Rational::from(1) / a[row][col];
+        a[row].iter_mut().for_each(|v| *v *= inv);
+        b[row] *= inv;
+
+        for r in 0..a.len() {
+            if r == row || is_zero(&a[r][col]) {
+                continue;
+            }
+            let factor = a[r][col];
+            let pivot_row = a[row].clone();
+            for (v, p) in a[r][col..].iter_mut().zip(&pivot_row[col..]) {
+                *v -= factor * *p;
+            }
+            let delta = factor * b[row];
+            b[r] -= delta;
+        }
+        pivots.push(col);
+        row += 1;
+    }
+
+    // Remaining rows are all zeros on the left, so they need a zero on the right too.
+    if b[row..].iter().any(|v| !is_zero(v)) {
+        return LinearSolution::None;
+    }
+
+    let mut solution = vec![Rational::from(0); n_vars];
+    for (r, &col) in pivots.iter().enumerate() {
+        solution[col] = b[r];
+    }
+    if pivots.len() == n_vars {
+        LinearSolution::Unique(solution)
+    } else {
+        let free = (0..n_vars).filter(|c| !pivots.contains(c)).collect();
+        LinearSolution::Infinite {
+            particular: solution,
+            free,
+        }
+    }
+}
+
+/// Solves the integer 2x2 system `a * [x, y] = b`.
+pub fn solve_2x2(a: [[i64; 2]; 2], b: [i64; 2]) -> LinearSolution {
+    solve_linear(
+        a.iter()
+            .map(|row| row.iter().copied().map(rational).collect())
+            .collect(),
+        b.into_iter().map(rational).collect(),
+    )
+}
+
+/// Extended Euclid: returns `(g, x, y)` with `a * x + b * y = g = gcd(a, b)` and `g >= 0`.
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+pub fn gcd(a: i64, b: i64) -> i64 {
+    ext_gcd(a, b).0
+}
+
+/// Inverse of `a` modulo `m`, in `0..m`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0, "modulus must be positive");
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// The congruences contradict each other.
+    NoSolution,
+    /// The lcm of the moduli does not fit in an `i64`.
+    Overflow,
+}
+
+impl Error for CrtError {}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrtError::NoSolution => write!(f, "the congruences contradict each other"),
+            CrtError::Overflow => write!(f, "the lcm of the moduli overflows an i64"),
+        }
+    }
+}
+
+/// Chinese remainder theorem: the smallest non-negative `x` with `x = r (mod m)` for every
+/// `(r, m)` of `congruences`, along with the modulus of the solution (the lcm of the moduli).
+///
+/// Moduli do not need to be coprime.
+///
+/// ```
+/// # use advent_of_code::math::{crt, CrtError};
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), Err(CrtError::NoSolution));
+/// ```
+pub fn crt(congruences: &[(i64, i64)]) -> Result<(i64, i64), CrtError> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for &(r, n) in congruences {
+        assert!(n > 0, "modulus must be positive");
+        let (r, n) = (r as i128, n as i128);
+        let m64 = i64::try_from(m).map_err(|_| CrtError::Overflow)?;
+        let (g, p, _) = ext_gcd(m64, n as i64);
+        let g = g as i128;
+        if (r - x) % g != 0 {
+            return Err(CrtError::NoSolution);
+        }
+        // x + m * k = r (mod n)  <=>  k = (r - x) / g * p (mod n / g)
+        let step = n / g;
+        let k = ((r - x) / g % step * (p as i128 % step)).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        x = x.rem_euclid(m);
+    }
+    let m = i64::try_from(m).map_err(|_| CrtError::Overflow)?;
+    // x < m
+    Ok((x as i64, m))
+}
+
+/// All the integer solutions of `a * x + b * y = c`:
+/// `(x0 + t * step_x, y0 - t * step_y)` for any integer `t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diophantine {
+    pub x0: i64,
+    pub y0: i64,
+    pub step_x: i64,
+    pub step_y: i64,
+}
+
+impl Diophantine {
+    /// Solves `a * x + b * y = c`, `None` if there is no integer solution.
+    /// `a` and `b` must not both be zero.
+    pub fn solve(a: i64, b: i64, c: i64) -> Option<Self> {
+        assert!(a != 0 || b != 0, "equation has no unknowns");
+        let (g, p, q) = ext_gcd(a, b);
+        if c % g != 0 {
+            return None;
+        }
+        let scale = (c / g) as i128;
+        Some(Self {
+            x0: i64::try_from(p as i128 * scale).ok()?,
+            y0: i64::try_from(q as i128 * scale).ok()?,
+            step_x: b / g,
+            step_y: a / g,
+        })
+    }
+
+    pub fn at(&self, t: i64) -> (i64, i64) {
+        (self.x0 + t * self.step_x, self.y0 - t * self.step_y)
+    }
+
+    /// Range of `t` for which both `x` and `y` are non-negative, if any.
+    pub fn non_negative(&self) -> Option<(i64, i64)> {
+        let (mut lo, mut hi) = (i64::MIN, i64::MAX);
+        // value + t * step >= 0
+        for (value, step) in [(self.x0, self.step_x), (self.y0, -self.step_y)] {
+            match step.signum() {
+                0 if value < 0 => return None,
+                0 => {}
+                1 => lo = lo.max(div_ceil(-value, step)),
+                _ => hi = hi.min(div_floor(-value, step)),
+            }
+        }
+        (lo <= hi).then_some((lo, hi))
+    }
+}
+
+fn div_floor(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+fn div_ceil(a: i64, b: i64) -> i64 {
+    -div_floor(-a, b)
+}
+
+/// Cheapest non-negative integer solution of the 2x2 system `a * [x, y] = b`, where `x` costs
+/// `cost[0]` and `y` costs `cost[1]`. Costs must be non-negative.
+///
+/// Dependent systems (parallel columns) fall back on the Diophantine equation of their first
+/// non-trivial row; since the cost is linear along it, the cheapest solution is at one end of
+/// the non-negative range.
+pub fn min_cost_2x2(a: [[i64; 2]; 2], b: [i64; 2], cost: [i64; 2]) -> Option<(i64, i64)> {
+    let price = |(x, y): (i64, i64)| cost[0] * x + cost[1] * y;
+    match solve_2x2(a, b) {
+        LinearSolution::None => None,
+        LinearSolution::Unique(sol) => {
+            let (x, y) = (to_integer(&sol[0])?, to_integer(&sol[1])?);
+            (x >= 0 && y >= 0).then_some((x, y))
+        }
+        LinearSolution::Infinite { .. } => {
+            let row = (0..2).find(|&r| a[r] != [0, 0])?;
+            let line = Diophantine::solve(a[row][0], a[row][1], b[row])?;
+            let (lo, hi) = line.non_negative()?;
+            // Both rows are multiples of each other, so `line` satisfies the other one as well.
+            // An unbounded end means a button that does not move the claw, never worth pushing.
+            [lo, hi]
+                .into_iter()
+                .filter(|&t| t != i64::MIN && t != i64::MAX)
+                .map(|t| line.at(t))
+                .min_by_key(|&p| price(p))
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    fn r(n: i64) -> Rational {
+        rational(n)
+    }
+
+    #[test]
+    fn solves_square_system() {
+        let a = vec![
+            vec![r(0), r(1), r(1)],
+            vec![r(2), r(0), r(1)],
+            vec![r(1), r(1), r(0)],
+        ];
+        let b = vec![r(5), r(5), r(3)];
+        assert_eq!(
+            solve_linear(a, b),
+            LinearSolution::Unique(vec![r(1), r(2), r(3)])
+        );
+        assert_eq!(
+            solve_2x2([[1, 1], [1, -1]], [1, 0]),
+            LinearSolution::Unique(vec![Rational::new(1u8, 2u8); 2])
+        );
+    }
+
+    #[test]
+    fn reports_degenerate_systems() {
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 7]), LinearSolution::None);
+        assert_eq!(
+            solve_2x2([[1, 2], [2, 4]], [3, 6]),
+            LinearSolution::Infinite {
+                particular: vec![r(3), r(0)],
+                free: vec![1],
+            }
+        );
+        assert_eq!(
+            solve_2x2([[0, 0], [0, 0]], [0, 0]),
+            LinearSolution::Infinite {
+                particular: vec![r(0), r(0)],
+                free: vec![0, 1],
+            }
+        );
+    }
+
+    #[test]
+    fn number_theory() {
+        assert_eq!(ext_gcd(240, 46), (2, -9, 47));
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Ok((7, 12)));
+        assert_eq!(crt(&[]), Ok((0, 1)));
+        let (p, q) = (4_294_967_291, 4_294_967_279);
+        assert_eq!(crt(&[(1, p), (2, q)]), Err(CrtError::Overflow));
+    }
+
+    #[test]
+    fn diophantine_non_negative() {
+        let line = Diophantine::solve(6, 10, 60).unwrap();
+        let (lo, hi) = line.non_negative().unwrap();
+        let mut sols: Vec<_> = (lo..=hi).map(|t| line.at(t)).collect();
+        sols.sort();
+        assert_eq!(sols, vec![(0, 6), (5, 3), (10, 0)]);
+        assert_eq!(Diophantine::solve(6, 10, 7), None);
+        assert_eq!(Diophantine::solve(6, 10, -2).unwrap().non_negative(), None);
+    }
+
+    #[test]
+    fn min_cost_handles_dependent_buttons() {
+        assert_eq!(
+            min_cost_2x2([[94, 22], [34, 67]], [8400, 5400], [3, 1]),
+            Some((80, 40))
+        );
+        // Button B moves twice as far as button A, at a third of the price.
+        assert_eq!(
+            min_cost_2x2([[1, 2], [1, 2]], [10, 10], [3, 1]),
+            Some((0, 5))
+        );
+        assert_eq!(min_cost_2x2([[2, 4], [2, 4]], [7, 7], [3, 1]), None);
+        assert_eq!(min_cost_2x2([[0, 2], [0, 2]], [4, 4], [3, 1]), Some((0, 2)));
+        assert_eq!(
+            min_cost_2x2([[1, 2], [1, 2]], [10, 10], [1, 3]),
+            Some((10, 0))
+        );
+    }
+}
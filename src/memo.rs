@@ -0,0 +1,236 @@
+//! Memoization helpers for recursive solvers.
+//!
+//! [`Memo`] is a cache that counts its hits and misses, and [`memoize!`](crate::memoize) wraps a free
+//! function with a per-thread [`Memo`]. All memos also feed global counters that the runner prints
+//! with `--verbose`.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::Hash,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+static GLOBAL_HITS: AtomicU64 = AtomicU64::new(0);
+static GLOBAL_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Hit and miss counters of a memo.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl MemoStats {
+    pub fn lookups(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    /// Share of lookups answered from the cache, between 0 and 1.
+    pub fn hit_rate(&self) -> f64 {
+        if self.lookups() == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / self.lookups() as f64
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits / {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+/// Counters summed over every memo since the last [`reset_global_stats`].
+pub fn global_stats() -> MemoStats {
+    MemoStats {
+        hits: GLOBAL_HITS.load(Ordering::Relaxed),
+        misses: GLOBAL_MISSES.load(Ordering::Relaxed),
+    }
+}
+
+pub fn reset_global_stats() {
+    GLOBAL_HITS.store(0, Ordering::Relaxed);
+    GLOBAL_MISSES.store(0, Ordering::Relaxed);
+}
+
+/// A memoization table from `K` to `V`.
+///
+/// ```
+/// # use advent_of_code::memo::Memo;
+/// let mut memo = Memo::new();
+/// let fib = memo.recurse(80u64, &|fib, n| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+/// assert_eq!(fib, 23416728348467685);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached value of `key`, counting the lookup as a hit or a miss.
+    pub fn lookup(&mut self, key: &K) -> Option<V> {
+        match self.cache.get(key) {
+            Some(value) => {
+                self.stats.hits += 1;
+                GLOBAL_HITS.fetch_add(1, Ordering::Relaxed);
+                Some(value.clone())
+            }
+            None => {
+                self.stats.misses += 1;
+                GLOBAL_MISSES.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.cache.insert(key, value);
+    }
+
+    /// Returns the cached value of `key`, or computes it with `f` and caches it.
+    /// `f` gets the memo back, so it can look up other keys while computing.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.lookup(&key) {
+            return value;
+        }
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Memoized recursion: computes `f(rec, key)` where every `rec(k)` call inside `f` goes
+    /// through this memo.
+    pub fn recurse<F>(&mut self, key: K, f: &F) -> V
+    where
+        K: Clone,
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(value) = self.lookup(&key) {
+            return value;
+        }
+        let value = f(&mut |k| self.recurse(k, f), key.clone());
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Drops the cached values, keeping the counters.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/// Wraps a free function with a per-thread [`Memo`](crate::memo::Memo) keyed by its arguments.
+///
+/// Arguments must be `Clone + Hash + Eq + 'static` and the return type `Clone + 'static`.
+/// The cache lives as long as the thread, so it is shared between calls (and parts).
+///
+/// ```
+/// advent_of_code::memoize! {
+///     fn paths(x: u64, y: u64) -> u64 {
+///         if x == 0 || y == 0 {
+///             return 1;
+///         }
+///         paths(x - 1, y) + paths(x, y - 1)
+///     }
+/// }
+/// assert_eq!(paths(16, 16), 601080390);
+/// ```
+#[macro_export]
+macro_rules! memoize {
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $body:block) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) -> $ret {
+            ::std::thread_local! {
+                static MEMO: ::std::cell::RefCell<$crate::memo::Memo<($($ty,)*), $ret>> =
+                    ::std::cell::RefCell::new($crate::memo::Memo::new());
+            }
+
+            let key = ($(::std::clone::Clone::clone(&$arg),)*);
+            if let Some(value) = MEMO.with(|memo| memo.borrow_mut().lookup(&key)) {
+                return value;
+            }
+            // NOTE: the memo must not stay borrowed while the body recurses.
+            #[allow(clippy::redundant_closure_call)]
+            let value: $ret = (move || $body)();
+            MEMO.with(|memo| memo.borrow_mut().insert(key, ::std::clone::Clone::clone(&value)));
+            value
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Memo;
+
+    #[test]
+    fn recurse_counts_hits_and_misses() {
+        let mut memo = Memo::new();
+        let fib = memo.recurse(10u64, &|fib, n| {
+            if n < 2 {
+                n
+            } else {
+                fib(n - 1) + fib(n - 2)
+            }
+        });
+        assert_eq!(fib, 55);
+        assert_eq!(memo.len(), 11);
+        assert_eq!(memo.stats().misses, 11);
+        assert_eq!(memo.stats().hits, 8);
+    }
+
+    #[test]
+    fn get_or_insert_with_caches() {
+        let mut memo: Memo<&str, usize> = Memo::new();
+        assert_eq!(memo.get_or_insert_with("abc", |_| 3), 3);
+        assert_eq!(memo.get_or_insert_with("abc", |_| unreachable!()), 3);
+        assert_eq!(memo.stats().hits, 1);
+    }
+
+    crate::memoize! {
+        fn collatz_len(n: u64) -> u32 {
+            match n {
+                1 => 1,
+                n if n % 2 == 0 => 1 + collatz_len(n / 2),
+                n => 1 + collatz_len(3 * n + 1),
+            }
+        }
+    }
+
+    #[test]
+    fn memoize_free_function() {
+        assert_eq!(collatz_len(27), 112);
+        assert_eq!(collatz_len(54), 113);
+    }
+}
@@ -0,0 +1,290 @@
+//! Small 2D patterns, with wildcard cells, searched for in a grid.
+//!
+//! A [`Pattern`] can be matched as is or under any of the 8 [`Transform`]s of the square.
+//! Transforms that give back the same shape are only tried once, so a symmetric pattern is not
+//! counted several times at the same place. Words in a straight line are [`Pattern::line`]s:
+//! a horizontal and a diagonal one cover the 8 directions under all transforms.
+use crate::{DirVec, Pos};
+
+/// A rotation by a number of quarter turns clockwise, after a mirror flip left to right if
+/// `flip` is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transform {
+    pub quarter_turns: u8,
+    pub flip: bool,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self {
+        quarter_turns: 0,
+        flip: false,
+    };
+
+    /// The 4 rotations.
+    pub fn rotations() -> impl Iterator<Item = Self> {
+        (0..4).map(|quarter_turns| Self {
+            quarter_turns,
+            flip: false,
+        })
+    }
+
+    /// The 4 rotations, then the 4 rotations of the mirror image.
+    pub fn all() -> impl Iterator<Item = Self> {
+        [false, true].into_iter().flat_map(|flip| {
+            (0..4).map(move |quarter_turns| Self {
+                quarter_turns,
+                flip,
+            })
+        })
+    }
+
+    fn apply(self, (dx, dy): (isize, isize)) -> (isize, isize) {
+        let mut offset = if self.flip { (-dx, dy) } else { (dx, dy) };
+        for _ in 0..self.quarter_turns % 4 {
+            // y goes down: right turns to down
+            offset = (-offset.1, offset.0);
+        }
+        offset
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pattern<T> {
+    /// Cells that must match, by offset from the top left corner of the smallest rectangle
+    /// around them: rows and columns of wildcards on the border do not count.
+    cells: Vec<((isize, isize), T)>,
+}
+
+/// Where a pattern was found: the top left corner of the transformed pattern, and the transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    pub pos: Pos,
+    pub transform: Transform,
+}
+
+impl<T> Pattern<T> {
+    /// Rows of cells, `None` being a wildcard.
+    pub fn new(rows: Vec<Vec<Option<T>>>) -> Self {
+        let cells = rows
+            .into_iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .filter_map(move |(x, cell)| cell.map(|c| ((x as isize, y as isize), c)))
+            })
+            .collect();
+        Self::normalized(cells)
+    }
+
+    /// `items` one after the other, each `step` away from the previous one.
+    pub fn line(items: impl IntoIterator<Item = T>, step: DirVec) -> Self {
+        let cells = items
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| ((i as isize * step.dx, i as isize * step.dy), item))
+            .collect();
+        Self::normalized(cells)
+    }
+
+    /// Shifts the cells so that the smallest offsets are 0.
+    fn normalized(mut cells: Vec<((isize, isize), T)>) -> Self {
+        let min_x = cells.iter().map(|((x, _), _)| *x).min().unwrap_or(0);
+        let min_y = cells.iter().map(|((_, y), _)| *y).min().unwrap_or(0);
+        for ((x, y), _) in &mut cells {
+            *x -= min_x;
+            *y -= min_y;
+        }
+        Self { cells }
+    }
+}
+
+impl Pattern<char> {
+    /// One row per line, `wildcard` matching any char.
+    ///
+    /// ```
+    /// # use advent_of_code::pattern::{Pattern, Transform};
+    /// let grid: Vec<Vec<char>> = ["MXS", "XAX", "MXS"].iter().map(|l| l.chars().collect()).collect();
+    /// let x_mas = Pattern::parse("M.S\n.A.\nM.S", '.');
+    /// assert_eq!(x_mas.find(&grid, Transform::all()).count(), 1);
+    /// ```
+    pub fn parse(s: &str, wildcard: char) -> Self {
+        Self::new(
+            s.lines()
+                .map(|l| l.chars().map(|c| (c != wildcard).then_some(c)).collect())
+                .collect(),
+        )
+    }
+}
+
+impl<T: PartialEq + Clone> Pattern<T> {
+    fn transformed(&self, transform: Transform) -> Self {
+        Self::normalized(
+            self.cells
+                .iter()
+                .map(|(offset, c)| (transform.apply(*offset), c.clone()))
+                .collect(),
+        )
+    }
+
+    fn same_shape(&self, other: &Self) -> bool {
+        self.cells.len() == other.cells.len() && self.cells.iter().all(|c| other.cells.contains(c))
+    }
+
+    /// Whether the pattern is found with its top left corner at `pos`.
+    pub fn matches_at(&self, grid: &[Vec<T>], pos: Pos) -> bool {
+        self.cells.iter().all(|&((dx, dy), ref c)| {
+            grid.get(pos.y + dy as usize)
+                .and_then(|row| row.get(pos.x + dx as usize))
+                .is_some_and(|cell| cell == c)
+        })
+    }
+
+    /// Adds the shapes of the pattern under `transforms` to `shapes`, skipping those already
+    /// there.
+    fn add_shapes(
+        &self,
+        shapes: &mut Vec<(Transform, Self)>,
+        transforms: impl IntoIterator<Item = Transform>,
+    ) {
+        for transform in transforms {
+            let shape = self.transformed(transform);
+            if !shapes.iter().any(|(_, other)| other.same_shape(&shape)) {
+                shapes.push((transform, shape));
+            }
+        }
+    }
+
+    /// Every match in `grid` under the given transforms, row by row. Transforms giving the same
+    /// shape as an earlier one are skipped.
+    pub fn find<'a>(
+        &self,
+        grid: &'a [Vec<T>],
+        transforms: impl IntoIterator<Item = Transform>,
+    ) -> impl Iterator<Item = Match> + 'a
+    where
+        T: 'a,
+    {
+        let mut shapes = Vec::new();
+        self.add_shapes(&mut shapes, transforms);
+        find_shapes(grid, shapes)
+    }
+}
+
+fn find_shapes<'a, T: PartialEq + Clone + 'a>(
+    grid: &'a [Vec<T>],
+    shapes: Vec<(Transform, Pattern<T>)>,
+) -> impl Iterator<Item = Match> + 'a {
+    (0..grid.len()).flat_map(move |y| {
+        let shapes = shapes.clone();
+        (0..grid[y].len()).flat_map(move |x| {
+            let pos = Pos { x, y };
+            shapes
+                .iter()
+                .filter(|(_, shape)| shape.matches_at(grid, pos))
+                .map(|&(transform, _)| Match { pos, transform })
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+/// Every occurrence of `word` in a straight line, in any of the 8 directions, each one found
+/// once (a word that reads the same both ways, or a single letter, included). The empty word
+/// is found nowhere.
+pub fn find_words<'a, T: PartialEq + Clone + 'a>(
+    grid: &'a [Vec<T>],
+    word: &[T],
+) -> impl Iterator<Item = Match> + 'a {
+    let mut shapes = Vec::new();
+    if !word.is_empty() {
+        for step in [DirVec { dx: 1, dy: 0 }, DirVec { dx: 1, dy: 1 }] {
+            Pattern::line(word.iter().cloned(), step).add_shapes(&mut shapes, Transform::all());
+        }
+    }
+    find_shapes(grid, shapes)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Vec<Vec<char>> {
+        input.lines().map(|l| l.chars().collect()).collect()
+    }
+
+    #[test]
+    fn transforms() {
+        assert_eq!(Transform::all().count(), 8);
+        let turn = Transform {
+            quarter_turns: 1,
+            flip: false,
+        };
+        assert_eq!(turn.apply((1, 0)), (0, 1));
+        let mirror = Transform {
+            quarter_turns: 0,
+            flip: true,
+        };
+        assert_eq!(mirror.apply((1, 2)), (-1, 2));
+    }
+
+    #[test]
+    fn wildcards_and_symmetries() {
+        let letters = grid("ABA\nxBy\nABA");
+        // symmetric under every transform: found once, not 8 times
+        let corners = Pattern::parse("A.A\n...\nA.A", '.');
+        assert_eq!(corners.find(&letters, Transform::all()).count(), 1);
+        let bar = Pattern::parse("B\nB", '.');
+        let found: Vec<Pos> = bar
+            .find(&letters, Transform::all())
+            .map(|m| m.pos)
+            .collect();
+        assert_eq!(found, vec![Pos { x: 1, y: 0 }, Pos { x: 1, y: 1 }]);
+
+        let corner = Pattern::parse("AB\n.y", '.');
+        let found: Vec<Match> = corner.find(&grid("BA\nyx"), Transform::all()).collect();
+        assert_eq!(
+            found,
+            vec![Match {
+                pos: Pos { x: 0, y: 0 },
+                transform: Transform {
+                    quarter_turns: 0,
+                    flip: true
+                }
+            }]
+        );
+        assert_eq!(
+            corner.find(&grid("BA\nyx"), [Transform::IDENTITY]).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn words_in_all_directions() {
+        let grid = grid("SAMX\n.A..\n..M.\n...X");
+        let found: Vec<Match> = find_words(&grid, &['X', 'M', 'A', 'S']).collect();
+        // left on the top row, and up left from the bottom right corner
+        assert_eq!(found.len(), 2);
+        assert_eq!(find_words(&grid, &['X', 'M', 'A', 'S', 'X']).count(), 0);
+
+        // the horizontal and the diagonal lines are the same shape for a single letter
+        let letters = vec![vec!['X', 'Y']];
+        assert_eq!(find_words(&letters, &['X']).count(), 1);
+        assert_eq!(find_words(&letters, &[]).count(), 0);
+        // read both ways, a palindrome is still found once per place
+        assert_eq!(find_words(&letters, &['X', 'Y', 'X']).count(), 0);
+        assert_eq!(
+            find_words(&[vec!['X', 'Y', 'X']], &['X', 'Y', 'X']).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn wildcard_border() {
+        let letters = grid("xAB\nyCD");
+        let pattern = Pattern::parse("..\n.C", '.');
+        let found: Vec<Match> = pattern.find(&letters, [Transform::IDENTITY]).collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].pos, Pos { x: 1, y: 1 });
+        assert!(pattern.matches_at(&letters, found[0].pos));
+    }
+}
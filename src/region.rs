@@ -0,0 +1,314 @@
+//! Connected regions of equal cells on a grid.
+//!
+//! [`Regions::new`] labels every cell with its region, two cells being connected when they are
+//! next to each other (not diagonally) and equal. Each [`Region`] comes with its area,
+//! perimeter, number of straight sides and bounding box; [`Regions::holes`] finds what it
+//! encloses.
+use std::collections::VecDeque;
+
+use crate::{Bound, Dir, Pos};
+
+/// Smallest rectangle holding a region, bounds included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BBox {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl BBox {
+    fn new(pos: Pos) -> Self {
+        Self { min: pos, max: pos }
+    }
+
+    fn extend(&mut self, pos: Pos) {
+        self.min.x = self.min.x.min(pos.x);
+        self.min.y = self.min.y.min(pos.y);
+        self.max.x = self.max.x.max(pos.x);
+        self.max.y = self.max.y.max(pos.y);
+    }
+
+    pub fn width(&self) -> usize {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (self.min.x..=self.max.x).contains(&pos.x) && (self.min.y..=self.max.y).contains(&pos.y)
+    }
+
+    /// Cells on the edges of the box.
+    fn border(&self) -> impl Iterator<Item = Pos> {
+        let (min, max) = (self.min, self.max);
+        (min.x..=max.x)
+            .flat_map(move |x| [Pos { x, y: min.y }, Pos { x, y: max.y }])
+            .chain((min.y..=max.y).flat_map(move |y| [Pos { x: min.x, y }, Pos { x: max.x, y }]))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<T> {
+    pub value: T,
+    /// First cell of the region in reading order.
+    pub start: Pos,
+    /// Number of cells.
+    pub area: usize,
+    /// Number of cell edges between the region and the rest of the grid (or its border).
+    pub perimeter: usize,
+    /// Number of straight fences around the region, holes included.
+    pub sides: usize,
+    pub bbox: BBox,
+}
+
+#[derive(Debug)]
+pub struct Regions<T> {
+    labels: Vec<Vec<usize>>,
+    regions: Vec<Region<T>>,
+    bound: Bound,
+}
+
+impl<T: Clone + Eq> Regions<T> {
+    /// Labels the regions of `grid`, by order of their first cell in reading order.
+    pub fn new(grid: &[Vec<T>]) -> Self {
+        let bound = Bound {
+            x_bound: grid.first().map_or(0, Vec::len),
+            y_bound: grid.len(),
+        };
+        let mut regions = Self {
+            labels: vec![vec![usize::MAX; bound.x_bound]; bound.y_bound],
+            regions: Vec::new(),
+            bound,
+        };
+        for y in 0..regions.bound.y_bound {
+            for x in 0..regions.bound.x_bound {
+                if regions.labels[y][x] == usize::MAX {
+                    regions.fill(grid, Pos { x, y });
+                }
+            }
+        }
+        for y in 0..regions.bound.y_bound {
+            for x in 0..regions.bound.x_bound {
+                let pos = Pos { x, y };
+                let (label, corners) = (regions.label(pos), regions.corners(pos));
+                regions.regions[label].sides += corners;
+            }
+        }
+        regions
+    }
+
+    /// Labels the region holding `start`, measuring its area, perimeter and bounding box.
+    fn fill(&mut self, grid: &[Vec<T>], start: Pos) {
+        let label = self.regions.len();
+        let value = grid[start.y][start.x].clone();
+        let mut region = Region {
+            value,
+            start,
+            area: 0,
+            perimeter: 0,
+            sides: 0,
+            bbox: BBox::new(start),
+        };
+        self.labels[start.y][start.x] = label;
+        let mut queue = VecDeque::from([start]);
+        while let Some(pos) = queue.pop_front() {
+            region.area += 1;
+            region.bbox.extend(pos);
+            for dir in Dir::all() {
+                match dir.apply_bounded(&pos, &self.bound) {
+                    Some(next) if grid[next.y][next.x] == region.value => {
+                        if self.labels[next.y][next.x] != label {
+                            self.labels[next.y][next.x] = label;
+                            queue.push_back(next);
+                        }
+                    }
+                    _ => region.perimeter += 1,
+                }
+            }
+        }
+        self.regions.push(region);
+    }
+}
+
+impl<T> Regions<T> {
+    /// Label of the region holding `pos`, an index into [`Regions::iter`].
+    pub fn label(&self, pos: Pos) -> usize {
+        self.labels[pos.y][pos.x]
+    }
+
+    pub fn get(&self, label: usize) -> &Region<T> {
+        &self.regions[label]
+    }
+
+    /// Number of regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Region<T>> {
+        self.regions.iter()
+    }
+
+    /// Cells of a region, in reading order.
+    pub fn cells(&self, label: usize) -> impl Iterator<Item = Pos> + '_ {
+        let bbox = self.regions[label].bbox;
+        (bbox.min.y..=bbox.max.y)
+            .flat_map(move |y| (bbox.min.x..=bbox.max.x).map(move |x| Pos { x, y }))
+            .filter(move |&pos| self.label(pos) == label)
+    }
+
+    /// Cells outside a region that cannot reach the border of the grid without crossing it,
+    /// grouped by hole. As for regions, cells touching by a corner are not connected.
+    pub fn holes(&self, label: usize) -> Vec<Vec<Pos>> {
+        let bbox = self.regions[label].bbox;
+        let mut outside = vec![vec![false; bbox.width()]; bbox.height()];
+        let local = |pos: Pos| (pos.x - bbox.min.x, pos.y - bbox.min.y);
+        let is_free = |pos: Pos| self.label(pos) != label;
+
+        // everything reachable from the cells of the box's edges is outside
+        let mut queue: VecDeque<Pos> = bbox.border().filter(|&pos| is_free(pos)).collect();
+        queue.iter().for_each(|&pos| {
+            let (x, y) = local(pos);
+            outside[y][x] = true;
+        });
+        self.flood(&mut queue, bbox, &mut outside, is_free, |_| {});
+
+        let mut holes = Vec::new();
+        for y in 0..bbox.height() {
+            for x in 0..bbox.width() {
+                let pos = Pos {
+                    x: bbox.min.x + x,
+                    y: bbox.min.y + y,
+                };
+                if outside[y][x] || !is_free(pos) {
+                    continue;
+                }
+                outside[y][x] = true;
+                let mut hole = vec![pos];
+                let mut queue = VecDeque::from([pos]);
+                self.flood(&mut queue, bbox, &mut outside, is_free, |pos| {
+                    hole.push(pos)
+                });
+                holes.push(hole);
+            }
+        }
+        holes
+    }
+
+    /// Breadth-first search within `bbox` through the cells accepted by `is_free`, marking them
+    /// in `seen`.
+    fn flood(
+        &self,
+        queue: &mut VecDeque<Pos>,
+        bbox: BBox,
+        seen: &mut [Vec<bool>],
+        is_free: impl Fn(Pos) -> bool,
+        mut visit: impl FnMut(Pos),
+    ) {
+        while let Some(pos) = queue.pop_front() {
+            for dir in Dir::all() {
+                let Some(next) = dir.apply(&pos).filter(|&next| bbox.contains(next)) else {
+                    continue;
+                };
+                let (x, y) = (next.x - bbox.min.x, next.y - bbox.min.y);
+                if !seen[y][x] && is_free(next) {
+                    seen[y][x] = true;
+                    visit(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    /// Corners of the region's outline at `pos`: as many as the sides they join.
+    fn corners(&self, pos: Pos) -> usize {
+        let label = self.label(pos);
+        let same = |pos: Option<Pos>| {
+            pos.and_then(|pos| self.bound.check(pos))
+                .is_some_and(|pos| self.label(pos) == label)
+        };
+        Dir::all()
+            .into_iter()
+            .filter(|&dir| {
+                let side = dir.rotate_right();
+                let ahead = same(dir.apply(&pos));
+                let beside = same(side.apply(&pos));
+                let diagonal = same(dir.apply(&pos).and_then(|p| side.apply(&p)));
+                // outer corner, or inner corner
+                (!ahead && !beside) || (ahead && beside && !diagonal)
+            })
+            .count()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Vec<Vec<char>> {
+        input.lines().map(|l| l.chars().collect()).collect()
+    }
+
+    #[test]
+    fn measures_regions() {
+        let regions = Regions::new(&grid("AAAA\nBBCD\nBBCC\nEEEC"));
+        let measures: Vec<_> = regions
+            .iter()
+            .map(|r| (r.value, r.area, r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            measures,
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+        let c = regions.label(Pos { x: 2, y: 1 });
+        assert_eq!(
+            regions.get(c).bbox,
+            BBox {
+                min: Pos { x: 2, y: 1 },
+                max: Pos { x: 3, y: 3 }
+            }
+        );
+        assert_eq!(regions.cells(c).count(), 4);
+    }
+
+    #[test]
+    fn counts_sides_of_holes() {
+        let regions = Regions::new(&grid("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA"));
+        assert_eq!(regions.len(), 3);
+        assert_eq!(regions.get(0).sides, 12);
+        assert_eq!(regions.holes(0).len(), 2);
+        assert!(regions.holes(1).is_empty());
+    }
+
+    #[test]
+    fn finds_holes() {
+        let regions = Regions::new(&grid("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO"));
+        assert_eq!(regions.len(), 5);
+        assert_eq!(
+            regions.holes(0),
+            vec![
+                vec![Pos { x: 1, y: 1 }],
+                vec![Pos { x: 3, y: 1 }],
+                vec![Pos { x: 1, y: 3 }],
+                vec![Pos { x: 3, y: 3 }],
+            ]
+        );
+
+        // a hole of several cells, and a gap open to the border
+        let regions = Regions::new(&grid("#####\n#..#.\n#.##.\n#####"));
+        let holes = regions.holes(0);
+        assert_eq!(holes.len(), 1);
+        assert_eq!(holes[0].len(), 3);
+    }
+}
@@ -0,0 +1,223 @@
+//! Cycle detection for deterministic simulations.
+//!
+//! A [`Simulation`] maps a state to the next one. Once a state repeats, the simulation loops
+//! forever, so any far-future state can be found by skipping whole periods: see [`nth_state`].
+use std::{collections::HashMap, hash::Hash, marker::PhantomData};
+
+pub trait Simulation {
+    type State: Clone;
+    /// What identifies a state: two states with equal keys must evolve the same way.
+    type Key: Eq;
+
+    fn step(&self, state: &Self::State) -> Self::State;
+
+    fn key(&self, state: &Self::State) -> Self::Key;
+
+    /// A period known in advance: `state(n + period) == state(n)` for every `n`.
+    fn period(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// A simulation made of a step function, where the state is its own key.
+pub struct StepFn<S, F> {
+    step: F,
+    _state: PhantomData<S>,
+}
+
+impl<S: Clone + Eq, F: Fn(&S) -> S> StepFn<S, F> {
+    pub fn new(step: F) -> Self {
+        Self {
+            step,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<S: Clone + Eq, F: Fn(&S) -> S> Simulation for StepFn<S, F> {
+    type State = S;
+    type Key = S;
+
+    fn step(&self, state: &S) -> S {
+        (self.step)(state)
+    }
+
+    fn key(&self, state: &S) -> S {
+        state.clone()
+    }
+}
+
+/// States from `prefix` on repeat every `period` steps, and `prefix` is the first one that
+/// does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Smallest step count reaching the same state as `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Runs `n` steps from `start`.
+pub fn advance<S: Simulation>(sim: &S, start: &S::State, n: usize) -> S::State {
+    let mut state = start.clone();
+    for _ in 0..n {
+        state = sim.step(&state);
+    }
+    state
+}
+
+/// Finds the first position where the prefix and the cycle meet, once `period` is known:
+/// walks two states `period` steps apart until they match.
+fn find_prefix<S: Simulation>(sim: &S, start: &S::State, period: usize) -> usize {
+    let mut slow = start.clone();
+    let mut fast = advance(sim, start, period);
+    let mut prefix = 0;
+    while sim.key(&slow) != sim.key(&fast) {
+        slow = sim.step(&slow);
+        fast = sim.step(&fast);
+        prefix += 1;
+    }
+    prefix
+}
+
+/// Floyd's tortoise and hare, giving up after `max_steps` steps of the hare.
+pub fn floyd<S: Simulation>(sim: &S, start: &S::State, max_steps: usize) -> Option<Cycle> {
+    let mut tortoise = sim.step(start);
+    let mut hare = sim.step(&tortoise);
+    let mut steps = 2;
+    while sim.key(&tortoise) != sim.key(&hare) {
+        if steps >= max_steps {
+            return None;
+        }
+        tortoise = sim.step(&tortoise);
+        hare = sim.step(&sim.step(&hare));
+        steps += 2;
+    }
+
+    // the hare is a multiple of the period ahead: find the smallest
+    let mut period = 1;
+    let mut probe = sim.step(&tortoise);
+    while sim.key(&tortoise) != sim.key(&probe) {
+        probe = sim.step(&probe);
+        period += 1;
+    }
+    Some(Cycle {
+        prefix: find_prefix(sim, start, period),
+        period,
+    })
+}
+
+/// Brent's algorithm: fewer steps than [`floyd`], giving up after `max_steps` steps.
+pub fn brent<S: Simulation>(sim: &S, start: &S::State, max_steps: usize) -> Option<Cycle> {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = sim.step(start);
+    let mut steps = 1;
+    while sim.key(&tortoise) != sim.key(&hare) {
+        if steps >= max_steps {
+            return None;
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = sim.step(&hare);
+        period += 1;
+        steps += 1;
+    }
+    Some(Cycle {
+        prefix: find_prefix(sim, start, period),
+        period,
+    })
+}
+
+/// First repeated state, remembering the key of every state seen. One pass, but memory grows
+/// with the prefix and period.
+pub fn first_repeat<S>(sim: &S, start: &S::State, max_steps: usize) -> Option<Cycle>
+where
+    S: Simulation,
+    S::Key: Hash,
+{
+    let mut seen = HashMap::new();
+    let mut state = start.clone();
+    for n in 0..=max_steps {
+        if let Some(prefix) = seen.insert(sim.key(&state), n) {
+            return Some(Cycle {
+                prefix,
+                period: n - prefix,
+            });
+        }
+        state = sim.step(&state);
+    }
+    None
+}
+
+/// State after `n` steps from `start`, skipping whole periods once a cycle is found (or known
+/// from [`Simulation::period`]).
+pub fn nth_state<S: Simulation>(sim: &S, start: &S::State, n: usize) -> S::State {
+    let steps = match sim.period() {
+        Some(period) => n % period,
+        None => brent(sim, start, n).map_or(n, |cycle| cycle.reduce(n)),
+    };
+    advance(sim, start, steps)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ...
+    fn rho() -> StepFn<u32, impl Fn(&u32) -> u32> {
+        StepFn::new(|&n: &u32| if n == 7 { 3 } else { n + 1 })
+    }
+
+    #[test]
+    fn detectors_agree() {
+        let expected = Some(Cycle {
+            prefix: 3,
+            period: 5,
+        });
+        assert_eq!(floyd(&rho(), &0, 100), expected);
+        assert_eq!(brent(&rho(), &0, 100), expected);
+        assert_eq!(first_repeat(&rho(), &0, 100), expected);
+        assert_eq!(brent(&rho(), &0, 4), None);
+
+        let pure_cycle = StepFn::new(|&n: &u8| (n + 1) % 4);
+        assert_eq!(
+            brent(&pure_cycle, &2, 100),
+            Some(Cycle {
+                prefix: 0,
+                period: 4
+            })
+        );
+        let fixed_point = StepFn::new(|_: &u8| 1);
+        assert_eq!(
+            floyd(&fixed_point, &1, 100),
+            Some(Cycle {
+                prefix: 0,
+                period: 1
+            })
+        );
+    }
+
+    #[test]
+    fn fast_forward() {
+        assert_eq!(nth_state(&rho(), &0, 2), 2);
+        assert_eq!(
+            nth_state(&rho(), &0, 1_000_000_000),
+            3 + (1_000_000_000 - 3) % 5
+        );
+        assert_eq!(advance(&rho(), &0, 13), 3);
+    }
+}
@@ -0,0 +1,47 @@
+//! Visual artifacts (pictures, maps...) that a solution hands back to the runner.
+//!
+//! Solutions call [`record`] with whatever helped them find the answer; the runner only collects
+//! artifacts of the first run of a part, and only prints them with `--verbose`. Outside of such
+//! a run, [`record`] does nothing, so benchmarks and tests do not pay for the rendering.
+use std::{
+    fmt::Display,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ARTIFACTS: Mutex<Vec<Artifact>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artifact {
+    pub title: String,
+    pub content: String,
+}
+
+/// Whether artifacts are being collected, to skip building expensive ones.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Renders `content` and keeps it for the runner, if artifacts are being collected.
+pub fn record(title: impl Into<String>, content: impl Display) {
+    if !is_enabled() {
+        return;
+    }
+    ARTIFACTS.lock().unwrap().push(Artifact {
+        title: title.into(),
+        content: content.to_string(),
+    });
+}
+
+/// Starts or stops collecting artifacts.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Artifacts recorded since the last call.
+pub fn take() -> Vec<Artifact> {
+    std::mem::take(&mut *ARTIFACTS.lock().unwrap())
+}
@@ -1,5 +1,11 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{all_days, all_years, run_multi::run_multi, Year};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(year: Option<Year>, is_release: bool) {
+    let years = year.map_or_else(all_years, |year| vec![year]);
+    let days_to_run = years
+        .into_iter()
+        .flat_map(|year| all_days().map(move |day| (year, day)))
+        .collect();
+
+    run_multi(&days_to_run, is_release, false);
 }
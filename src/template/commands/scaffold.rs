@@ -1,79 +1,263 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    iter,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{get_bin_dir, get_bin_name, get_data_dir, Day, Year};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
-    }
-    file.truncate(true).write(true).open(path)
+const MANIFEST_PATH: &str = "Cargo.toml";
+
+/// What scaffolding does with a single file.
+enum Action {
+    /// The file does not exist yet and is created.
+    Create,
+    /// The file exists and is replaced, after being copied to `backup`.
+    Replace { backup: String },
+    /// The file exists and `contents` are appended to it.
+    Append,
+    /// The file exists and is left untouched.
+    Skip,
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+struct Step {
+    label: &'static str,
+    path: String,
+    contents: String,
+    action: Action,
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// First of `{path}.bak`, `{path}.bak.1`, `{path}.bak.2`... that does not exist, so that
+/// scaffolding again never replaces an earlier backup.
+fn backup_path(path: &str) -> String {
+    let base = format!("{path}.bak");
+    iter::once(base.clone())
+        .chain((1..).map(|n| format!("{base}.{n}")))
+        .find(|backup| !Path::new(backup).exists())
+        .unwrap()
+}
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+impl Step {
+    fn new(label: &'static str, path: String, contents: String, replace: bool) -> Self {
+        let action = if !Path::new(&path).exists() {
+            Action::Create
+        } else if replace {
+            Action::Replace {
+                backup: backup_path(&path),
+            }
+        } else {
+            Action::Skip
+        };
+
+        Self {
+            label,
+            path,
+            contents,
+            action,
         }
-    };
-
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+    }
+
+    fn append(label: &'static str, path: String, contents: String) -> Self {
+        Self {
+            label,
+            path,
+            contents,
+            action: Action::Append,
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+    }
+
+    fn created_label(&self) -> String {
+        if self.contents.is_empty() {
+            format!("empty {}", self.label)
+        } else {
+            self.label.to_string()
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    fn describe(&self) -> String {
+        match &self.action {
+            Action::Create => format!("create {} \"{}\"", self.created_label(), self.path),
+            Action::Replace { backup } => format!(
+                "replace {} \"{}\" (backup in \"{}\")",
+                self.label, self.path, backup
+            ),
+            Action::Append => format!("add {} to \"{}\"", self.label, self.path),
+            Action::Skip => format!("skip existing {} \"{}\"", self.label, self.path),
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    }
+}
+
+/// Files touched so far, so that a failing step can undo the previous ones.
+#[derive(Default)]
+struct Journal {
+    created_dirs: Vec<PathBuf>,
+    created: Vec<String>,
+    replaced: Vec<(String, String)>,
+    appended: Vec<(String, String)>,
+}
+
+impl Journal {
+    fn apply(&mut self, step: &Step) -> Result<(), io::Error> {
+        match &step.action {
+            Action::Create => {
+                if let Some(dir) = Path::new(&step.path).parent() {
+                    self.create_dirs(dir)?;
+                }
+                let mut file = OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(&step.path)?;
+                self.created.push(step.path.clone());
+                file.write_all(step.contents.as_bytes())
+            }
+            Action::Replace { backup } => {
+                // refuse to replace a backup that appeared since the plan was made
+                OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(backup)?;
+                if let Err(e) = fs::copy(&step.path, backup) {
+                    _ = fs::remove_file(backup);
+                    return Err(e);
+                }
+                self.replaced.push((step.path.clone(), backup.clone()));
+                fs::write(&step.path, &step.contents)
+            }
+            Action::Append => {
+                let previous = fs::read_to_string(&step.path)?;
+                self.appended.push((step.path.clone(), previous));
+                OpenOptions::new()
+                    .append(true)
+                    .open(&step.path)?
+                    .write_all(step.contents.as_bytes())
+            }
+            Action::Skip => Ok(()),
+        }
+    }
+
+    /// Creates `dir` and its missing ancestors, e.g. the directories of a new year.
+    fn create_dirs(&mut self, dir: &Path) -> Result<(), io::Error> {
+        let mut missing: Vec<&Path> = dir.ancestors().take_while(|d| !d.exists()).collect();
+        while let Some(dir) = missing.pop() {
+            fs::create_dir(dir)?;
+            self.created_dirs.push(dir.to_path_buf());
         }
+        Ok(())
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    fn rollback(self) {
+        for path in self.created.iter().rev() {
+            match fs::remove_file(path) {
+                Ok(()) => println!("Removed \"{path}\""),
+                Err(e) => eprintln!("Failed to remove \"{path}\": {e}"),
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+        for dir in self.created_dirs.iter().rev() {
+            match fs::remove_dir(dir) {
+                Ok(()) => println!("Removed \"{}\"", dir.display()),
+                Err(e) => eprintln!("Failed to remove \"{}\": {e}", dir.display()),
+            }
+        }
+        for (path, backup) in self.replaced.iter().rev() {
+            match fs::rename(backup, path) {
+                Ok(()) => println!("Restored \"{path}\" from \"{backup}\""),
+                Err(e) => eprintln!("Failed to restore \"{path}\" from \"{backup}\": {e}"),
+            }
+        }
+        for (path, previous) in self.appended.iter().rev() {
+            match fs::write(path, previous) {
+                Ok(()) => println!("Restored \"{path}\""),
+                Err(e) => eprintln!("Failed to restore \"{path}\": {e}"),
+            }
+        }
+    }
+}
+
+/// Lists the files to scaffold for `day` of `year`.
+/// Input and example files are never replaced, the module file only with `overwrite`.
+fn plan(year: Year, day: Day, overwrite: bool) -> Vec<Step> {
+    let module_path = format!("{}/{day}.rs", get_bin_dir(year));
+
+    let mut steps = vec![
+        Step::new(
+            "module file",
+            module_path.clone(),
+            MODULE_TEMPLATE
+                .replace("%YEAR%", &year.to_string())
+                .replace("%DAY_NUMBER%", &day.into_inner().to_string()),
+            overwrite,
+        ),
+        Step::new(
+            "input file",
+            format!("{}/{day}.txt", get_data_dir(year, "inputs")),
+            String::new(),
+            false,
+        ),
+        Step::new(
+            "example file",
+            format!("{}/{day}.txt", get_data_dir(year, "examples")),
+            String::new(),
+            false,
+        ),
+    ];
+
+    // binaries in year directories are not discovered by cargo and need a manifest entry.
+    let bin_name = get_bin_name(year, day);
+    let manifest = fs::read_to_string(MANIFEST_PATH).unwrap_or_default();
+    if !manifest.contains(&format!("name = \"{bin_name}\"")) {
+        steps.push(Step::append(
+            "binary target",
+            MANIFEST_PATH.into(),
+            format!("\n[[bin]]\nname = \"{bin_name}\"\npath = \"{module_path}\"\n"),
+        ));
+    }
+
+    steps
+}
+
+pub fn handle(year: Year, day: Day, overwrite: bool, dry_run: bool) {
+    let steps = plan(year, day, overwrite);
+
+    if dry_run {
+        for step in &steps {
+            println!("Would {}", step.describe());
+        }
+        return;
+    }
+
+    let mut journal = Journal::default();
+
+    for step in &steps {
+        if let Err(e) = journal.apply(step) {
+            eprintln!("Failed to {}: {e}", step.describe());
+            journal.rollback();
             process::exit(1);
         }
+
+        match &step.action {
+            Action::Create => {
+                println!("Created {} \"{}\"", step.created_label(), step.path);
+            }
+            Action::Replace { backup } => {
+                println!(
+                    "Replaced {} \"{}\" (backup in \"{}\")",
+                    step.label, step.path, backup
+                );
+            }
+            Action::Append => {
+                println!("Added {} to \"{}\"", step.label, step.path);
+            }
+            Action::Skip => {
+                println!("Skipped existing {} \"{}\"", step.label, step.path);
+            }
+        }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
 }
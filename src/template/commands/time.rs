@@ -2,24 +2,37 @@ use std::collections::HashSet;
 
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, all_years, readme_benchmarks, Day, Year};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub fn handle(year: Option<Year>, day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
+    let years = match (year, day) {
+        (Some(year), _) => vec![year],
+        // a single day without an explicit year refers to the default year.
+        (None, Some(_)) => Year::from_env().into_iter().collect(),
+        (None, None) => all_years(),
+    };
+
+    let days_to_run: HashSet<(Year, Day)> = years
+        .into_iter()
+        .flat_map(|year| {
+            day.map_or_else(
+                || {
+                    if run_all {
+                        all_days().map(|day| (year, day)).collect()
+                    } else {
+                        // when the `--all` flag is not set, filter out days that are fully benched.
+                        all_days()
+                            .filter(|day| !stored_timings.is_day_complete(year, *day))
+                            .map(|day| (year, day))
+                            .collect()
+                    }
+                },
+                |day| vec![(year, day)],
+            )
+        })
+        .collect();
 
     let timings = run_multi(&days_to_run, true, true).unwrap();
 
@@ -1,15 +1,18 @@
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod artifact;
 pub mod commands;
 pub mod runner;
 
 pub use day::*;
+pub use year::*;
 
 mod day;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -17,41 +20,83 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(get_data_dir(year, folder))
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join(folder)
+        .join(get_data_dir(year, folder))
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Directory holding the data of a year, e.g. `data/2024/inputs`.
+#[must_use]
+pub fn get_data_dir(year: Year, folder: &str) -> String {
+    format!("data/{year}/{folder}")
+}
+
+/// Directory holding the solution binaries of a year, e.g. `src/bin/2024`.
+#[must_use]
+pub fn get_bin_dir(year: Year) -> String {
+    format!("src/bin/{year}")
+}
+
+/// Name of the cargo binary target of a solution, e.g. `2024-01`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
+/// Years that have a solution directory, in ascending order.
+#[must_use]
+pub fn all_years() -> Vec<Year> {
+    let Ok(entries) = fs::read_dir("src/bin") else {
+        return vec![];
+    };
+
+    let mut years: Vec<Year> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            if !entry.file_type().ok()?.is_dir() {
+                return None;
+            }
+            entry.file_name().to_str()?.parse().ok()
+        })
+        .collect();
+
+    years.sort_unstable();
+    years
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        /// The year of the puzzle.
+        const YEAR: $crate::template::Year = $crate::year!($year);
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -61,8 +106,8 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = $crate::template::read_file("inputs", YEAR, DAY);
+            $( run_part($func, &input, YEAR, DAY, $part); )*
         }
     };
 }
@@ -3,7 +3,7 @@
 use std::{fs, io};
 
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{get_bin_dir, Day, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -26,8 +26,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./{}/{day}.rs", get_bin_dir(year))
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -55,23 +55,30 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    for (year, timings) in timings.by_year() {
+        lines.push(String::new());
+        lines.push(format!("{prefix}# {year}"));
+        lines.push(String::new());
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+
+        let year_millis = timings.total_millis();
+
+        for timing in timings.data {
+            let path = get_path_for_bin(year, timing.day);
+            lines.push(format!(
+                "| [Day {}]({}) | `{}` | `{}` |",
+                timing.day.into_inner(),
+                path,
+                timing.part_1.unwrap_or_else(|| "-".into()),
+                timing.part_2.unwrap_or_else(|| "-".into())
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("_Total {year}: {year_millis:.2}ms_"));
     }
 
     lines.push(String::new());
@@ -100,24 +107,27 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{day, template::timings::Timing, template::timings::Timings, year};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
                 },
                 Timing {
+                    year: year!(2024),
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
                 },
                 Timing {
+                    year: year!(2024),
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
@@ -167,11 +177,15 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
+            "### 2024",
+            "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024/02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024/04.rs) | `40ms` | `50ms` |",
+            "",
+            "_Total 2024: 190000.00ms_",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +194,28 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn groups_benchmarks_by_year() {
+        let mut timings = get_mock_timings();
+        timings.data.insert(
+            0,
+            Timing {
+                year: year!(2023),
+                day: day!(25),
+                part_1: Some("1ms".into()),
+                part_2: None,
+                total_nanos: 1e+6,
+            },
+        );
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+
+        let pos_2023 = s.find("### 2023").unwrap();
+        let pos_2024 = s.find("### 2024").unwrap();
+        assert!(pos_2023 < pos_2024);
+        assert!(s.contains("| [Day 25](./src/bin/2023/25.rs) | `1ms` | `-` |"));
+        assert!(s.contains("_Total 2023: 1.00ms_"));
+    }
 }
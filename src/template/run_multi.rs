@@ -1,38 +1,48 @@
 use std::{collections::HashSet, io};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
-    all_days,
+    all_days, get_bin_dir,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+pub fn run_multi(
+    days_to_run: &HashSet<(Year, Day)>,
+    is_release: bool,
+    is_timed: bool,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
-
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    // NOTE: use non-duplicate, sorted day values, grouped by year.
+    let mut years: Vec<Year> = days_to_run.iter().map(|(year, _)| *year).collect();
+    years.sort_unstable();
+    years.dedup();
+
+    for year in years {
+        all_days()
+            .filter(|day| days_to_run.contains(&(year, *day)))
+            .for_each(|day| {
+                if need_space {
+                    println!();
+                }
+                need_space = true;
+
+                println!("{ANSI_BOLD}{year} Day {day}{ANSI_RESET}");
+                println!("-----------");
+
+                let output = child_commands::run_solution(year, day, is_timed, is_release).unwrap();
+
+                if output.is_empty() {
+                    println!("Not solved.");
+                } else {
+                    let val = child_commands::parse_exec_time(&output, year, day);
+                    timings.push(val);
+                }
+            });
+    }
 
     if is_timed {
         let timings = Timings { data: timings };
@@ -60,15 +70,15 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./{}/{day}.rs", get_bin_dir(year))
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use crate::template::{get_bin_name, Day, Year};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -77,14 +87,19 @@ pub mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = get_bin_name(year, day);
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
@@ -128,8 +143,9 @@ pub mod child_commands {
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    pub fn parse_exec_time(output: &[String], year: Year, day: Day) -> super::Timing {
         let mut timings = super::Timing {
+            year,
             day,
             part_1: None,
             part_2: None,
@@ -207,7 +223,7 @@ pub mod child_commands {
     mod tests {
         use super::parse_exec_time;
 
-        use crate::day;
+        use crate::{day, year};
 
         #[test]
         fn parses_execution_times() {
@@ -217,6 +233,7 @@ pub mod child_commands {
                     "Part 2: 10 (74.13ms @ 99999 samples)".into(),
                     "".into(),
                 ],
+                year!(2024),
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
@@ -232,6 +249,7 @@ pub mod child_commands {
                     "Part 2: 10s (100ms @ 1 samples)".into(),
                     "".into(),
                 ],
+                year!(2024),
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
@@ -247,6 +265,7 @@ pub mod child_commands {
                     "Part 2: ✖        ".into(),
                     "".into(),
                 ],
+                year!(2024),
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
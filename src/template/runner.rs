@@ -1,4 +1,5 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
@@ -6,19 +7,43 @@ use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::memo::{self, MemoStats};
+use crate::template::artifact::{self, Artifact};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, Day, Year, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
     let part_str = format!("Part {part}");
-
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let memo_stats: Cell<Option<MemoStats>> = Cell::new(None);
+    let artifacts: RefCell<Vec<Artifact>> = RefCell::new(vec![]);
+    let verbose = env::args().any(|x| x == "--verbose");
+
+    memo::reset_global_stats();
+    artifact::take();
+    artifact::set_enabled(verbose);
+    let (result, duration, samples) = run_timed(func, input, |result| {
+        // NOTE: the hook runs right after the first execution, before benching.
+        memo_stats.set(Some(memo::global_stats()));
+        artifact::set_enabled(false);
+        artifacts.replace(artifact::take());
+        print_result(result, &part_str, "");
+    });
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
+    if verbose {
+        print_memo_stats(memo_stats.get(), &part_str);
+        print_artifacts(&artifacts.into_inner(), &part_str);
+    }
+
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, year, day, part);
     }
 }
 
@@ -129,11 +154,28 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+fn print_memo_stats(stats: Option<MemoStats>, part: &str) {
+    match stats {
+        Some(stats) if stats.lookups() > 0 => {
+            println!("{ANSI_ITALIC}{part} memo: {stats}{ANSI_RESET}");
+        }
+        _ => {}
+    }
+}
+
+fn print_artifacts(artifacts: &[Artifact], part: &str) {
+    for artifact in artifacts {
+        println!("{ANSI_ITALIC}{part} {}:{ANSI_RESET}", artifact.title);
+        println!("{}", artifact.content.trim_end());
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
@@ -165,5 +207,5 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(aoc_cli::submit(year, day, part, &result.to_string()))
 }
@@ -1,13 +1,14 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{Day, Year};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
+    pub year: Year,
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
@@ -46,12 +47,15 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data
+                .iter()
+                .any(|t| t.year == timing.year && t.day == timing.day)
+            {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| (t.year, t.day));
         Timings { data }
     }
 
@@ -60,10 +64,30 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    pub fn is_day_complete(&self, year: Year, day: Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.year == year && t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    }
+
+    /// Group timings by year, in ascending order.
+    pub fn by_year(&self) -> Vec<(Year, Self)> {
+        let mut groups: Vec<(Year, Self)> = vec![];
+
+        for timing in &self.data {
+            match groups.iter_mut().find(|(year, _)| *year == timing.year) {
+                Some((_, group)) => group.data.push(timing.clone()),
+                None => groups.push((
+                    timing.year,
+                    Timings {
+                        data: vec![timing.clone()],
+                    },
+                )),
+            }
+        }
+
+        groups.sort_unstable_by_key(|(year, _)| *year);
+        groups
     }
 }
 
@@ -111,6 +135,7 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
@@ -145,6 +170,13 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
+        // timings stored before years were tracked belong to the default year.
+        let year = match json.get("year") {
+            Some(v) => v.get::<String>().and_then(|year| Year::from_str(year).ok()),
+            None => Year::from_env(),
+        }
+        .ok_or("Expected timing.year to be a Year struct.")?;
+
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
@@ -167,6 +199,7 @@ impl TryFrom<&JsonValue> for Timing {
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
         Ok(Timing {
+            year,
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
@@ -179,7 +212,7 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, year};
 
     use super::{Timing, Timings};
 
@@ -187,18 +220,21 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
                 },
                 Timing {
+                    year: year!(2024),
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
                 },
                 Timing {
+                    year: year!(2024),
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
@@ -209,20 +245,28 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{day, template::timings::Timings, year};
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2023", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
+            assert_eq!(timing.year, year!(2023));
             assert_eq!(timing.day, day!(1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_without_year() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].year, year!(2024));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -272,12 +316,14 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            year,
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
@@ -285,13 +331,14 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
@@ -299,13 +346,14 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: None,
                     part_2: None,
@@ -313,7 +361,7 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), false);
         }
     }
 
@@ -321,6 +369,7 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            year,
         };
 
         use super::get_mock_timings;
@@ -330,6 +379,7 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(3),
                     part_1: None,
                     part_2: None,
@@ -350,6 +400,7 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(2),
                     part_1: None,
                     part_2: None,
@@ -365,6 +416,28 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_same_day_of_other_years() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    year: year!(2023),
+                    day: day!(2),
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0_f64,
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].year, year!(2023));
+            assert_eq!(merged.data[0].day, day!(2));
+            assert_eq!(merged.data[1].year, year!(2024));
+            assert_eq!(merged.data[1].day, day!(1));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
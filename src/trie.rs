@@ -0,0 +1,306 @@
+//! Prefix tree over a set of patterns, to split strings into a sequence of patterns.
+//!
+//! [`Trie::count_segmentations`] counts the ways to write a string as patterns put end to end,
+//! with checked arithmetic; [`Trie::segmentations`] lists them and
+//! [`Trie::segmentation_at`] picks one by index, e.g. a random one.
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Child by next byte.
+    children: Vec<(u8, usize)>,
+    /// Whether a pattern ends here.
+    terminal: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    patterns: usize,
+}
+
+/// There are more segmentations than a `u64` can count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountOverflow;
+
+impl Error for CountOverflow {}
+
+impl Display for CountOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "too many segmentations to count")
+    }
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+            patterns: 0,
+        }
+    }
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `pattern`, returning whether it was missing. The empty pattern is ignored, as it
+    /// would make every string split in infinitely many ways.
+    pub fn insert(&mut self, pattern: &str) -> bool {
+        if pattern.is_empty() {
+            return false;
+        }
+        let mut node = 0;
+        for &byte in pattern.as_bytes() {
+            node = match self.child(node, byte) {
+                Some(child) => child,
+                None => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.push((byte, child));
+                    child
+                }
+            };
+        }
+        let added = !self.nodes[node].terminal;
+        self.nodes[node].terminal = true;
+        self.patterns += usize::from(added);
+        added
+    }
+
+    fn child(&self, node: usize, byte: u8) -> Option<usize> {
+        self.nodes[node]
+            .children
+            .iter()
+            .find_map(|&(b, child)| (b == byte).then_some(child))
+    }
+
+    /// Number of patterns.
+    pub fn len(&self) -> usize {
+        self.patterns
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns == 0
+    }
+
+    pub fn contains(&self, pattern: &str) -> bool {
+        let mut node = 0;
+        for &byte in pattern.as_bytes() {
+            match self.child(node, byte) {
+                Some(child) => node = child,
+                None => return false,
+            }
+        }
+        self.nodes[node].terminal
+    }
+
+    /// Lengths of the patterns that `s` starts with, shortest first.
+    pub fn prefixes<'a>(&'a self, s: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.byte_prefixes(s.as_bytes())
+    }
+
+    /// Same as [`Trie::prefixes`] on bytes, so that it can start inside a char: no pattern
+    /// matches there, a `str` never starting with a continuation byte.
+    fn byte_prefixes<'a>(&'a self, bytes: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        let mut node = Some(0);
+        bytes.iter().enumerate().filter_map(move |(i, &byte)| {
+            node = self.child(node?, byte);
+            node.filter(|&n| self.nodes[n].terminal).map(|_| i + 1)
+        })
+    }
+
+    /// For every byte offset of `s`, whether what follows it can be segmented.
+    fn suffix_viable(&self, s: &str) -> Vec<bool> {
+        let bytes = s.as_bytes();
+        let mut viable = vec![false; s.len() + 1];
+        viable[s.len()] = true;
+        for i in (0..s.len()).rev() {
+            viable[i] = self.byte_prefixes(&bytes[i..]).any(|len| viable[i + len]);
+        }
+        viable
+    }
+
+    /// Whether `s` can be written as patterns put end to end.
+    pub fn can_segment(&self, s: &str) -> bool {
+        self.suffix_viable(s)[0]
+    }
+
+    /// For every byte offset of `s`, the number of ways to segment what follows it, `None` if
+    /// there are too many to count. An offset only overflows if one it reaches does, so an
+    /// overflow that the start cannot reach does not spoil its count.
+    fn suffix_counts(&self, s: &str) -> Vec<Option<u64>> {
+        let bytes = s.as_bytes();
+        let mut ways = vec![Some(0u64); s.len() + 1];
+        ways[s.len()] = Some(1);
+        for i in (0..s.len()).rev() {
+            ways[i] = self
+                .byte_prefixes(&bytes[i..])
+                .try_fold(0u64, |sum, len| sum.checked_add(ways[i + len]?));
+        }
+        ways
+    }
+
+    /// Number of ways to write `s` as patterns put end to end.
+    ///
+    /// ```
+    /// # use advent_of_code::trie::Trie;
+    /// let trie: Trie = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"].into_iter().collect();
+    /// assert_eq!(trie.count_segmentations("brwrr"), Ok(2));
+    /// assert_eq!(trie.count_segmentations("ubwu"), Ok(0));
+    /// ```
+    pub fn count_segmentations(&self, s: &str) -> Result<u64, CountOverflow> {
+        self.suffix_counts(s)[0].ok_or(CountOverflow)
+    }
+
+    /// Every segmentation of `s`, depth first. Dead ends are skipped, so each step yields
+    /// a segmentation.
+    pub fn segmentations<'a>(&'a self, s: &'a str) -> impl Iterator<Item = Vec<&'a str>> + 'a {
+        let viable = self.suffix_viable(s);
+        let mut stack: Vec<(usize, Vec<&'a str>)> = Vec::new();
+        if viable[0] {
+            stack.push((0, Vec::new()));
+        }
+        std::iter::from_fn(move || {
+            while let Some((start, parts)) = stack.pop() {
+                if start == s.len() {
+                    return Some(parts);
+                }
+                let lens: Vec<usize> = self.byte_prefixes(&s.as_bytes()[start..]).collect();
+                // pushed longest first, to pop shortest first
+                for &len in lens.iter().rev() {
+                    if viable[start + len] {
+                        let mut parts = parts.clone();
+                        parts.push(&s[start..start + len]);
+                        stack.push((start + len, parts));
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    /// The `index`-th segmentation of `s` in the order of [`Trie::segmentations`], if there are
+    /// that many. Drawing `index` uniformly below the count samples segmentations uniformly.
+    pub fn segmentation_at<'a>(
+        &self,
+        s: &'a str,
+        mut index: u64,
+    ) -> Result<Option<Vec<&'a str>>, CountOverflow> {
+        let ways = self.suffix_counts(s);
+        if index >= ways[0].ok_or(CountOverflow)? {
+            return Ok(None);
+        }
+        let mut parts = Vec::new();
+        let mut start = 0;
+        while start < s.len() {
+            // skip the segmentations starting with shorter patterns
+            let len = self
+                .byte_prefixes(&s.as_bytes()[start..])
+                .find(|&len| {
+                    // reachable from the start, so counted without overflow
+                    let here = ways[start + len].unwrap();
+                    index = match index.checked_sub(here) {
+                        Some(rest) => rest,
+                        None => return true,
+                    };
+                    false
+                })
+                .expect("an index below the count always finds a pattern");
+            parts.push(&s[start..start + len]);
+            start += len;
+        }
+        Ok(Some(parts))
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Trie {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        let mut trie = Self::new();
+        iter.into_iter()
+            .for_each(|pattern| _ = trie.insert(pattern));
+        trie
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{CountOverflow, Trie};
+
+    fn towels() -> Trie {
+        ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn insert_and_prefixes() {
+        let mut trie = towels();
+        assert_eq!(trie.len(), 8);
+        assert!(!trie.insert("rb"));
+        assert!(!trie.insert(""));
+        assert!(trie.contains("bwu"));
+        assert!(!trie.contains("bw"));
+        assert_eq!(trie.prefixes("bwurrg").collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(trie.prefixes("x").count(), 0);
+    }
+
+    #[test]
+    fn counts_and_lists() {
+        let trie = towels();
+        assert_eq!(trie.count_segmentations("gbbr"), Ok(4));
+        assert!(trie.can_segment("rrbgbr"));
+        assert!(!trie.can_segment("bbrgwb"));
+
+        let all: Vec<Vec<&str>> = trie.segmentations("gbbr").collect();
+        assert_eq!(
+            all,
+            vec![
+                vec!["g", "b", "b", "r"],
+                vec!["g", "b", "br"],
+                vec!["gb", "b", "r"],
+                vec!["gb", "br"],
+            ]
+        );
+        for (i, seg) in all.iter().enumerate() {
+            assert_eq!(
+                trie.segmentation_at("gbbr", i as u64),
+                Ok(Some(seg.clone()))
+            );
+        }
+        assert_eq!(trie.segmentation_at("gbbr", 4), Ok(None));
+        assert_eq!(trie.segmentations("ubwu").count(), 0);
+    }
+
+    #[test]
+    fn non_ascii() {
+        let trie: Trie = ["é", "ab", "a", "b"].into_iter().collect();
+        assert_eq!(trie.count_segmentations("éab"), Ok(2));
+        assert!(!trie.can_segment("aü"));
+        assert_eq!(
+            trie.segmentations("abé").collect::<Vec<_>>(),
+            vec![vec!["a", "b", "é"], vec!["ab", "é"]]
+        );
+        assert_eq!(trie.segmentation_at("éab", 1), Ok(Some(vec!["é", "ab"])));
+    }
+
+    #[test]
+    fn detects_overflow() {
+        // Fibonacci many ways: F(100) does not fit in a u64
+        let trie: Trie = ["a", "aa"].into_iter().collect();
+        assert_eq!(
+            trie.count_segmentations(&"a".repeat(90)),
+            Ok(4660046610375530309)
+        );
+        assert_eq!(
+            trie.count_segmentations(&"a".repeat(100)),
+            Err(CountOverflow)
+        );
+        // the suffix overflows, but no segmentation gets past the 'b'
+        assert_eq!(
+            trie.count_segmentations(&format!("b{}", "a".repeat(100))),
+            Ok(0)
+        );
+    }
+}
@@ -0,0 +1,542 @@
+//! The 3-bit computer of 2024 day 17.
+//!
+//! A program is a list of 3-bit numbers read in (opcode, operand) pairs. [`assemble`] and
+//! [`disassemble`] convert between those bytes and a mnemonic listing, one instruction per line:
+//!
+//! ```text
+//! bst a      ; combo operands 4..=6 are written as the register they read
+//! bxl 1      ; literal operands are plain numbers
+//! out b
+//! jnz 0
+//! ```
+//!
+//! [`Machine`] runs a program, either to completion or step by step with breakpoints, a step
+//! limit to catch infinite loops, and a [`Step`] record of every executed instruction.
+//! [`Machine::find_quine`] searches for the value of A that makes a program output itself.
+use std::{collections::BTreeSet, error::Error, fmt::Display};
+
+pub use quine::*;
+
+mod quine;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Registers {
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandType {
+    Literal,
+    /// 0..=3 are literals, 4, 5 and 6 read registers A, B and C, 7 is invalid.
+    Combo,
+    /// The operand is read but ignored.
+    Legacy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    /// `A <- A >> combo`
+    Adv,
+    /// `B <- B ^ literal`
+    Bxl,
+    /// `B <- combo % 8`
+    Bst,
+    /// `PC <- literal` if `A != 0`
+    Jnz,
+    /// `B <- B ^ C`
+    Bxc,
+    /// outputs `combo % 8`
+    Out,
+    /// `B <- A >> combo`
+    Bdv,
+    /// `C <- A >> combo`
+    Cdv,
+}
+
+impl Opcode {
+    pub fn all() -> [Self; 8] {
+        [
+            Self::Adv,
+            Self::Bxl,
+            Self::Bst,
+            Self::Jnz,
+            Self::Bxc,
+            Self::Out,
+            Self::Bdv,
+            Self::Cdv,
+        ]
+    }
+
+    pub fn from_opcode(opcode: u8) -> Option<Self> {
+        Self::all().get(opcode as usize).copied()
+    }
+
+    pub fn opcode(self) -> u8 {
+        self as u8
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Adv => "adv",
+            Self::Bxl => "bxl",
+            Self::Bst => "bst",
+            Self::Jnz => "jnz",
+            Self::Bxc => "bxc",
+            Self::Out => "out",
+            Self::Bdv => "bdv",
+            Self::Cdv => "cdv",
+        }
+    }
+
+    pub fn operand_type(self) -> OperandType {
+        match self {
+            Self::Bxl | Self::Jnz => OperandType::Literal,
+            Self::Bxc => OperandType::Legacy,
+            Self::Adv | Self::Bst | Self::Out | Self::Bdv | Self::Cdv => OperandType::Combo,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    /// Decodes the instruction at `pc`, `None` past the end of the program.
+    pub fn decode(program: &[u8], pc: usize) -> Option<Self> {
+        let opcode = Opcode::from_opcode(*program.get(pc)?)?;
+        let operand = *program.get(pc + 1)?;
+        Some(Self { opcode, operand })
+    }
+
+    /// Value of the operand, `None` for the invalid combo operand 7.
+    pub fn read(&self, regs: &Registers) -> Option<usize> {
+        match self.opcode.operand_type() {
+            OperandType::Literal => Some(self.operand as usize),
+            OperandType::Legacy => Some(0),
+            OperandType::Combo => match self.operand {
+                0..=3 => Some(self.operand as usize),
+                4 => Some(regs.a),
+                5 => Some(regs.b),
+                6 => Some(regs.c),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operand = match (self.opcode.operand_type(), self.operand) {
+            (OperandType::Combo, 4) => "a".to_string(),
+            (OperandType::Combo, 5) => "b".to_string(),
+            (OperandType::Combo, 6) => "c".to_string(),
+            (_, operand) => operand.to_string(),
+        };
+        write!(f, "{} {}", self.opcode.mnemonic(), operand)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsmError {
+    UnknownMnemonic { line: usize, mnemonic: String },
+    MissingOperand { line: usize },
+    InvalidOperand { line: usize, operand: String },
+}
+
+impl Error for AsmError {}
+
+impl Display for AsmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AsmError::UnknownMnemonic { line, mnemonic } => {
+                write!(f, "line {line}: unknown mnemonic `{mnemonic}`")
+            }
+            AsmError::MissingOperand { line } => write!(f, "line {line}: missing operand"),
+            AsmError::InvalidOperand { line, operand } => {
+                write!(f, "line {line}: invalid operand `{operand}`")
+            }
+        }
+    }
+}
+
+/// Assembles a mnemonic listing into program bytes. Blank lines and `;` comments are skipped.
+///
+/// ```
+/// # use advent_of_code::vm::{assemble, disassemble};
+/// let program = assemble("adv 1\nout a ; print A\njnz 0").unwrap();
+/// assert_eq!(program, vec![0, 1, 5, 4, 3, 0]);
+/// assert_eq!(disassemble(&program), "adv 1\nout a\njnz 0\n");
+/// ```
+pub fn assemble(text: &str) -> Result<Vec<u8>, AsmError> {
+    let mut program = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let code = line.split(';').next().unwrap().trim();
+        if code.is_empty() {
+            continue;
+        }
+        let mut words = code.split_whitespace();
+        let mnemonic = words.next().unwrap();
+        let opcode = Opcode::all()
+            .into_iter()
+            .find(|op| op.mnemonic().eq_ignore_ascii_case(mnemonic))
+            .ok_or_else(|| AsmError::UnknownMnemonic {
+                line: line_no,
+                mnemonic: mnemonic.to_string(),
+            })?;
+        let word = words
+            .next()
+            .ok_or(AsmError::MissingOperand { line: line_no })?;
+        let invalid = || AsmError::InvalidOperand {
+            line: line_no,
+            operand: word.to_string(),
+        };
+        let operand = match (opcode.operand_type(), word.to_ascii_lowercase().as_str()) {
+            (OperandType::Combo, "a") => 4,
+            (OperandType::Combo, "b") => 5,
+            (OperandType::Combo, "c") => 6,
+            _ => word
+                .parse()
+                .ok()
+                .filter(|&n: &u8| n < 8)
+                .ok_or_else(invalid)?,
+        };
+        if words.next().is_some() {
+            return Err(invalid());
+        }
+        program.extend([opcode.opcode(), operand]);
+    }
+    Ok(program)
+}
+
+/// Mnemonic listing of program bytes, one instruction per line. A trailing odd byte or an
+/// invalid opcode is kept as a `; raw N` comment.
+pub fn disassemble(program: &[u8]) -> String {
+    let mut listing = String::new();
+    for (pc, pair) in program.chunks(2).enumerate() {
+        match Instruction::decode(program, pc * 2) {
+            Some(instr) => listing.push_str(&format!("{instr}\n")),
+            None => pair
+                .iter()
+                .for_each(|byte| listing.push_str(&format!("; raw {byte}\n"))),
+        }
+    }
+    listing
+}
+
+/// One executed instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunError {
+    /// The step limit was reached, the program probably loops forever.
+    StepLimit { steps: usize },
+    /// Combo operand 7 at `pc`.
+    InvalidOperand { pc: usize },
+}
+
+impl Error for RunError {}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::StepLimit { steps } => write!(f, "step limit reached after {steps} steps"),
+            RunError::InvalidOperand { pc } => write!(f, "invalid combo operand 7 at pc {pc}"),
+        }
+    }
+}
+
+/// Why [`Machine::resume`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    /// About to execute the instruction at this breakpoint.
+    Breakpoint(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    pub regs: Registers,
+    pub pc: usize,
+    program: Vec<u8>,
+    output: Vec<u8>,
+    steps: usize,
+    step_limit: Option<usize>,
+    breakpoints: BTreeSet<usize>,
+}
+
+/// Parses the puzzle input: the three registers, a blank line, then the program.
+impl From<&str> for Machine {
+    fn from(value: &str) -> Self {
+        let (regs, prog) = value.split_once("\n\n").unwrap();
+
+        let mut regs_line = regs.lines();
+        let mut next_reg = || {
+            let (_, reg) = regs_line.next().unwrap().split_once(": ").unwrap();
+            reg.trim().parse().unwrap()
+        };
+        let regs = Registers {
+            a: next_reg(),
+            b: next_reg(),
+            c: next_reg(),
+        };
+
+        let (_, prog) = prog.split_once(": ").unwrap();
+        let program = prog
+            .trim()
+            .split(',')
+            .map(|tb| tb.parse().unwrap())
+            .collect();
+
+        Self::new(program, regs)
+    }
+}
+
+impl Machine {
+    pub fn new(program: Vec<u8>, regs: Registers) -> Self {
+        Self {
+            regs,
+            pc: 0,
+            program,
+            output: Vec::new(),
+            steps: 0,
+            step_limit: None,
+            breakpoints: BTreeSet::new(),
+        }
+    }
+
+    /// Fails runs with [`RunError::StepLimit`] once `limit` instructions were executed.
+    pub fn with_step_limit(mut self, limit: usize) -> Self {
+        self.step_limit = Some(limit);
+        self
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    /// Everything output since the machine was created or [`reset`](Self::reset).
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Number of instructions executed.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn is_halted(&self) -> bool {
+        Instruction::decode(&self.program, self.pc).is_none()
+    }
+
+    /// Restarts the program with the given registers, keeping breakpoints and step limit.
+    pub fn reset(&mut self, regs: Registers) {
+        self.regs = regs;
+        self.pc = 0;
+        self.output.clear();
+        self.steps = 0;
+    }
+
+    pub fn add_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    pub fn remove_breakpoint(&mut self, pc: usize) -> bool {
+        self.breakpoints.remove(&pc)
+    }
+
+    /// Executes one instruction, `None` if the program has halted.
+    pub fn step(&mut self) -> Result<Option<Step>, RunError> {
+        let Some(instruction) = Instruction::decode(&self.program, self.pc) else {
+            return Ok(None);
+        };
+        if self.step_limit.is_some_and(|limit| self.steps >= limit) {
+            return Err(RunError::StepLimit { steps: self.steps });
+        }
+
+        let pc = self.pc;
+        let before = self.regs;
+        let value = instruction
+            .read(&self.regs)
+            .ok_or(RunError::InvalidOperand { pc })?;
+        // Shifting by the register width or more clears everything.
+        let shifted = u32::try_from(value)
+            .ok()
+            .and_then(|shift| self.regs.a.checked_shr(shift))
+            .unwrap_or(0);
+        let mut next_pc = pc + 2;
+        let mut output = None;
+        match instruction.opcode {
+            Opcode::Adv => self.regs.a = shifted,
+            Opcode::Bdv => self.regs.b = shifted,
+            Opcode::Cdv => self.regs.c = shifted,
+            Opcode::Bxl => self.regs.b ^= value,
+            Opcode::Bst => self.regs.b = value % 8,
+            Opcode::Jnz if self.regs.a != 0 => next_pc = value,
+            Opcode::Jnz => {}
+            Opcode::Bxc => self.regs.b ^= self.regs.c,
+            Opcode::Out => output = Some((value % 8) as u8),
+        }
+        self.pc = next_pc;
+        self.steps += 1;
+        self.output.extend(output);
+
+        Ok(Some(Step {
+            pc,
+            instruction,
+            before,
+            after: self.regs,
+            output,
+        }))
+    }
+
+    /// Runs until the program halts and returns its whole output.
+    pub fn run(&mut self) -> Result<Vec<u8>, RunError> {
+        while self.step()?.is_some() {}
+        Ok(self.output.clone())
+    }
+
+    /// Runs until the program halts, recording every step.
+    pub fn trace(&mut self) -> Result<Vec<Step>, RunError> {
+        let mut steps = Vec::new();
+        while let Some(step) = self.step()? {
+            steps.push(step);
+        }
+        Ok(steps)
+    }
+
+    /// Runs until the next output, `None` if the program halts first.
+    pub fn run_until_output(&mut self) -> Result<Option<u8>, RunError> {
+        while let Some(step) = self.step()? {
+            if step.output.is_some() {
+                return Ok(step.output);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Runs until the program halts or reaches a breakpoint. The instruction under the current
+    /// pc always executes, so resuming from a breakpoint moves past it.
+    pub fn resume(&mut self) -> Result<Stop, RunError> {
+        if self.step()?.is_none() {
+            return Ok(Stop::Halted);
+        }
+        loop {
+            if self.breakpoints.contains(&self.pc) && !self.is_halted() {
+                return Ok(Stop::Breakpoint(self.pc));
+            }
+            if self.step()?.is_none() {
+                return Ok(Stop::Halted);
+            }
+        }
+    }
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self.regs)?;
+        writeln!(f, "Program:")?;
+        f.write_str(&disassemble(&self.program))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    #[test]
+    fn runs_example() {
+        let mut machine = Machine::from(EXAMPLE);
+        assert_eq!(machine.run(), Ok(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]));
+        assert!(machine.is_halted());
+    }
+
+    #[test]
+    fn assembler_round_trip() {
+        let program = vec![2, 4, 1, 1, 7, 5, 0, 3, 4, 7, 1, 6, 5, 5, 3, 0];
+        let listing = disassemble(&program);
+        assert_eq!(
+            listing,
+            "bst a\nbxl 1\ncdv b\nadv 3\nbxc 7\nbxl 6\nout b\njnz 0\n"
+        );
+        assert_eq!(assemble(&listing), Ok(program));
+        assert_eq!(disassemble(&[5, 7, 3]), "out 7\n; raw 3\n");
+    }
+
+    #[test]
+    fn assembler_errors() {
+        assert_eq!(
+            assemble("adv 1\nmul 2"),
+            Err(AsmError::UnknownMnemonic {
+                line: 2,
+                mnemonic: "mul".to_string()
+            })
+        );
+        assert_eq!(
+            assemble("bxl a"),
+            Err(AsmError::InvalidOperand {
+                line: 1,
+                operand: "a".to_string()
+            })
+        );
+        assert_eq!(assemble("out"), Err(AsmError::MissingOperand { line: 1 }));
+    }
+
+    #[test]
+    fn traces_registers() {
+        let program = assemble("bst a\nbxl 3\nout b").unwrap();
+        let mut machine = Machine::new(program, Registers { a: 13, b: 0, c: 0 });
+        let trace = machine.trace().unwrap();
+        assert_eq!(trace.len(), 3);
+        assert_eq!(trace[0].after.b, 5);
+        assert_eq!(trace[1].before.b, 5);
+        assert_eq!(trace[1].after.b, 6);
+        assert_eq!(trace[2].output, Some(6));
+        assert_eq!(trace[2].pc, 4);
+    }
+
+    #[test]
+    fn step_limit_catches_infinite_loop() {
+        let program = assemble("out a\njnz 0").unwrap();
+        let mut machine =
+            Machine::new(program, Registers { a: 1, b: 0, c: 0 }).with_step_limit(100);
+        assert_eq!(machine.run(), Err(RunError::StepLimit { steps: 100 }));
+        assert_eq!(machine.output().len(), 50);
+    }
+
+    #[test]
+    fn breakpoints_and_outputs() {
+        let mut machine = Machine::from(EXAMPLE);
+        machine.add_breakpoint(4);
+        assert_eq!(machine.resume(), Ok(Stop::Breakpoint(4)));
+        assert_eq!(machine.output(), &[4]);
+        assert_eq!(machine.resume(), Ok(Stop::Breakpoint(4)));
+        assert_eq!(machine.output(), &[4, 6]);
+        assert!(machine.remove_breakpoint(4));
+        assert_eq!(machine.run_until_output(), Ok(Some(3)));
+        assert_eq!(machine.resume(), Ok(Stop::Halted));
+        assert_eq!(machine.run_until_output(), Ok(None));
+
+        let mut machine = Machine::new(vec![2, 7], Registers::default());
+        assert_eq!(machine.step(), Err(RunError::InvalidOperand { pc: 0 }));
+    }
+}
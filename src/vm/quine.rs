@@ -0,0 +1,324 @@
+//! Finding a value of register A for which a program outputs a copy of itself.
+use std::{error::Error, fmt::Display};
+
+use super::{Instruction, Machine, Opcode, OperandType, Registers, RunError};
+
+/// Steps after which a run of the bit search is considered to loop forever.
+const STEP_LIMIT: usize = 1 << 16;
+
+/// Structure of a program that [`Machine::find_quine`] can search 3 bits (or so) at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopShape {
+    /// Bits dropped from A on each iteration.
+    pub shift: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuineError {
+    NoSolution,
+    Run(RunError),
+}
+
+impl Error for QuineError {}
+
+impl Display for QuineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuineError::NoSolution => {
+                write!(f, "no value of register A makes the program output itself")
+            }
+            QuineError::Run(e) => write!(f, "program failed while searching: {e}"),
+        }
+    }
+}
+
+/// A register value with some unknown bits.
+#[derive(Debug, Clone, Copy)]
+struct Bits {
+    known: usize,
+    value: usize,
+}
+
+impl Bits {
+    fn exact(value: usize) -> Self {
+        Self { known: !0, value }
+    }
+
+    /// Only the lowest `bits` bits of `value` are known.
+    fn low(value: usize, bits: u32) -> Self {
+        let known = usize::MAX.checked_shr(usize::BITS - bits).unwrap_or(0);
+        Self {
+            known,
+            value: value & known,
+        }
+    }
+
+    fn get(self) -> Option<usize> {
+        (self.known == !0).then_some(self.value)
+    }
+
+    fn xor(self, other: Self) -> Self {
+        let known = self.known & other.known;
+        Self {
+            known,
+            value: (self.value ^ other.value) & known,
+        }
+    }
+
+    fn low3(self) -> Self {
+        Self {
+            known: self.known | !7,
+            value: self.value & 7,
+        }
+    }
+
+    fn shr(self, shift: usize) -> Self {
+        match u32::try_from(shift) {
+            Ok(shift) if shift < usize::BITS => Self {
+                // Bits shifted in from the top are zeros.
+                known: (self.known >> shift) | !(usize::MAX >> shift),
+                value: self.value >> shift,
+            },
+            _ => Self::exact(0),
+        }
+    }
+
+    fn is_zero(self) -> Option<bool> {
+        if self.value != 0 {
+            Some(false)
+        } else {
+            self.get().map(|_| true)
+        }
+    }
+}
+
+impl Machine {
+    /// Recognises programs made of a single loop that outputs once, drops a fixed number of bits
+    /// of A with its only `adv`, and ends with `jnz 0`. B and C must be written before being
+    /// read, so that each output only depends on what is left of A at the start of its
+    /// iteration.
+    pub fn loop_shape(&self) -> Option<LoopShape> {
+        let instrs: Vec<Instruction> = (0..self.program.len())
+            .step_by(2)
+            .map(|pc| Instruction::decode(&self.program, pc))
+            .collect::<Option<_>>()?;
+        let (last, body) = instrs.split_last()?;
+        if last.opcode != Opcode::Jnz || last.operand != 0 {
+            return None;
+        }
+        let count = |opcode| body.iter().filter(|i| i.opcode == opcode).count();
+        if count(Opcode::Jnz) != 0 || count(Opcode::Out) != 1 || count(Opcode::Adv) != 1 {
+            return None;
+        }
+
+        let (mut b_set, mut c_set) = (false, false);
+        let mut shift = None;
+        for instr in body {
+            let combo = instr.opcode.operand_type() == OperandType::Combo;
+            let reads_b =
+                matches!(instr.opcode, Opcode::Bxl | Opcode::Bxc) || (combo && instr.operand == 5);
+            let reads_c = instr.opcode == Opcode::Bxc || (combo && instr.operand == 6);
+            if (reads_b && !b_set) || (reads_c && !c_set) {
+                return None;
+            }
+            match instr.opcode {
+                Opcode::Adv if (1..=3).contains(&instr.operand) => {
+                    shift = Some(instr.operand as u32)
+                }
+                Opcode::Adv => return None,
+                Opcode::Bst | Opcode::Bdv => b_set = true,
+                Opcode::Cdv => c_set = true,
+                _ => {}
+            }
+        }
+        Some(LoopShape { shift: shift? })
+    }
+
+    /// Smallest value of A for which the program outputs itself.
+    ///
+    /// Programs with a [`LoopShape`] are solved chunk by chunk from the last output, the others
+    /// with the slower [`find_quine_by_bits`](Self::find_quine_by_bits).
+    ///
+    /// ```
+    /// # use advent_of_code::vm::Machine;
+    /// let machine = Machine::from("Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0");
+    /// assert_eq!(machine.find_quine(), Ok(117440));
+    /// ```
+    pub fn find_quine(&self) -> Result<usize, QuineError> {
+        match self.loop_shape() {
+            Some(shape) => self.find_quine_by_shape(shape),
+            None => self.find_quine_by_bits(),
+        }
+    }
+
+    /// Output `i` only depends on `A >> (i * shift)`, so A is built from its highest chunk,
+    /// matching outputs from the last one. Chunks are tried in ascending order, so the first
+    /// value found is the smallest.
+    pub fn find_quine_by_shape(&self, shape: LoopShape) -> Result<usize, QuineError> {
+        match self.program.len().checked_sub(1) {
+            Some(last) => self
+                .quine_chunks(shape.shift, last, 0)?
+                .ok_or(QuineError::NoSolution),
+            None => Err(QuineError::NoSolution),
+        }
+    }
+
+    fn quine_chunks(&self, shift: u32, idx: usize, a: usize) -> Result<Option<usize>, QuineError> {
+        if a.leading_zeros() < shift {
+            return Ok(None);
+        }
+        for low in 0..1 << shift {
+            let new_a = a << shift | low;
+            // A zero top chunk would stop the program before the last output.
+            if new_a == 0 && idx > 0 {
+                continue;
+            }
+            let mut machine = self.clone();
+            machine.reset(Registers {
+                a: new_a,
+                ..self.regs
+            });
+            if machine.run_until_output().map_err(QuineError::Run)? != Some(self.program[idx]) {
+                continue;
+            }
+            if idx > 0 {
+                if let Some(a) = self.quine_chunks(shift, idx - 1, new_a)? {
+                    return Ok(Some(a));
+                }
+            } else if machine.run().map_err(QuineError::Run)? == self.program {
+                return Ok(Some(new_a));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Shape-agnostic search: assigns the bits of A from the lowest up, and drops a partial
+    /// assignment as soon as running the program with the other bits unknown outputs a value
+    /// that differs from the program (or too many values).
+    pub fn find_quine_by_bits(&self) -> Result<usize, QuineError> {
+        let mut best = None;
+        self.quine_bits(0, 0, &mut best);
+        best.ok_or(QuineError::NoSolution)
+    }
+
+    fn quine_bits(&self, bits: u32, a: usize, best: &mut Option<usize>) {
+        // Unknown bits can only make A bigger.
+        if best.is_some_and(|best| best <= a) {
+            return;
+        }
+        let mut machine = Machine::new(self.program.clone(), Registers { a, ..self.regs })
+            .with_step_limit(STEP_LIMIT);
+        if machine.run().is_ok_and(|out| out == self.program) {
+            *best = Some(a);
+            return;
+        }
+        if bits == usize::BITS || !self.partial_run_matches(Bits::low(a, bits)) {
+            return;
+        }
+        self.quine_bits(bits + 1, a, best);
+        self.quine_bits(bits + 1, a | 1 << bits, best);
+    }
+
+    /// Runs the program on a partially known A, until it halts or needs an unknown value to
+    /// carry on. `false` if it outputs anything the program does not start with.
+    fn partial_run_matches(&self, a: Bits) -> bool {
+        let (mut ra, mut rb, mut rc) = (a, Bits::exact(self.regs.b), Bits::exact(self.regs.c));
+        let mut pc = 0;
+        let mut outputs = 0;
+        for _ in 0..STEP_LIMIT {
+            let Some(instr) = Instruction::decode(&self.program, pc) else {
+                return outputs == self.program.len();
+            };
+            let value = match (instr.opcode.operand_type(), instr.operand) {
+                (OperandType::Combo, 4) => ra,
+                (OperandType::Combo, 5) => rb,
+                (OperandType::Combo, 6) => rc,
+                (OperandType::Combo, 7) => return false,
+                (_, operand) => Bits::exact(operand as usize),
+            };
+            pc += 2;
+            match instr.opcode {
+                Opcode::Adv | Opcode::Bdv | Opcode::Cdv => {
+                    let Some(shift) = value.get() else {
+                        return true;
+                    };
+                    let shifted = ra.shr(shift);
+                    match instr.opcode {
+                        Opcode::Adv => ra = shifted,
+                        Opcode::Bdv => rb = shifted,
+                        _ => rc = shifted,
+                    }
+                }
+                Opcode::Bxl => rb = rb.xor(value),
+                Opcode::Bst => rb = value.low3(),
+                Opcode::Jnz => match ra.is_zero() {
+                    None => return true,
+                    Some(false) => pc = instr.operand as usize,
+                    Some(true) => {}
+                },
+                Opcode::Bxc => rb = rb.xor(rc),
+                Opcode::Out => {
+                    let out = value.low3();
+                    let Some(&expected) = self.program.get(outputs) else {
+                        return false;
+                    };
+                    if (out.value ^ expected as usize) & out.known != 0 {
+                        return false;
+                    }
+                    outputs += 1;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::vm::{assemble, Machine, Registers};
+
+    use super::{LoopShape, QuineError};
+
+    fn machine(program: &str) -> Machine {
+        Machine::new(assemble(program).unwrap(), Registers::default())
+    }
+
+    #[test]
+    fn detects_loop_shape() {
+        let input = Machine::new(
+            vec![2, 4, 1, 1, 7, 5, 0, 3, 4, 7, 1, 6, 5, 5, 3, 0],
+            Registers::default(),
+        );
+        assert_eq!(input.loop_shape(), Some(LoopShape { shift: 3 }));
+        // B is read before being written, so it carries over between iterations.
+        assert_eq!(machine("bxl 1\nadv 3\nout b\njnz 0").loop_shape(), None);
+        assert_eq!(machine("adv 1\nadv 2\nout a\njnz 0").loop_shape(), None);
+        assert_eq!(machine("adv 3\nout a\njnz 2").loop_shape(), None);
+    }
+
+    #[test]
+    fn both_searches_agree() {
+        let example = machine("adv 3\nout a\njnz 0");
+        assert_eq!(example.find_quine(), Ok(117440));
+        assert_eq!(example.find_quine_by_bits(), Ok(117440));
+    }
+
+    #[test]
+    fn falls_back_on_bit_search() {
+        // Two shifts in a row: three bits per iteration, but not in a single `adv`.
+        let split_shift = machine("adv 1\nadv 2\nout a\njnz 0");
+        assert_eq!(split_shift.find_quine(), Ok(7512128));
+    }
+
+    #[test]
+    fn reports_missing_solution() {
+        assert_eq!(
+            machine("adv 3\nout 1\njnz 0").find_quine(),
+            Err(QuineError::NoSolution)
+        );
+        assert_eq!(
+            machine("out 0\njnz 0").find_quine(),
+            Err(QuineError::NoSolution)
+        );
+    }
+}